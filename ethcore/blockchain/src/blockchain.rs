@@ -139,10 +139,13 @@ pub trait BlockProvider {
 	/// Get the block body (uncles and transactions).
 	fn block_body(&self, hash: &H256) -> Option<encoded::Body>;
 
+	/// Number of first block whose header carries a base fee (EIP-1559).
+	fn eip1559_transition(&self) -> BlockNumber;
+
 	/// Get a list of uncles for a given block.
 	/// Returns None if block does not exist.
 	fn uncles(&self, hash: &H256) -> Option<Vec<Header>> {
-		self.block_body(hash).map(|body| body.uncles())
+		self.block_body(hash).map(|body| body.uncles(self.eip1559_transition()))
 	}
 
 	/// Get a list of uncle hashes for a given block.
@@ -237,6 +240,8 @@ pub struct BlockChain {
 	pending_block_hashes: RwLock<HashMap<BlockNumber, H256>>,
 	pending_block_details: RwLock<HashMap<H256, BlockDetails>>,
 	pending_transaction_addresses: RwLock<HashMap<H256, Option<TransactionAddress>>>,
+
+	eip1559_transition: BlockNumber,
 }
 
 impl BlockProvider for BlockChain {
@@ -246,6 +251,10 @@ impl BlockProvider for BlockChain {
 		self.db.key_value().exists_with_cache(db::COL_EXTRA, &self.block_details, hash)
 	}
 
+	fn eip1559_transition(&self) -> BlockNumber {
+		self.eip1559_transition
+	}
+
 	fn first_block(&self) -> Option<H256> {
 		self.first_block.clone()
 	}
@@ -457,7 +466,7 @@ impl<'a> Iterator for AncestryWithMetadataIter<'a> {
 		} else {
 			let details = self.chain.block_details(&self.current);
 			let header = self.chain.block_header_data(&self.current)
-				.map(|h| h.decode().expect("Stored block header data is valid RLP; qed"));
+				.map(|h| h.decode(self.chain.eip1559_transition).expect("Stored block header data is valid RLP; qed"));
 
 			match (details, header) {
 				(Some(details), Some(header)) => {
@@ -547,6 +556,7 @@ impl BlockChain {
 			pending_block_hashes: RwLock::new(HashMap::new()),
 			pending_block_details: RwLock::new(HashMap::new()),
 			pending_transaction_addresses: RwLock::new(HashMap::new()),
+			eip1559_transition: config.eip1559_transition,
 		};
 
 		// load best block
@@ -596,7 +606,7 @@ impl BlockChain {
 			let mut best_block = bc.best_block.write();
 			*best_block = BestBlock {
 				total_difficulty: best_block_total_difficulty,
-				header: best_block_rlp.decode_header(),
+				header: best_block_rlp.decode_header(bc.eip1559_transition),
 				block: best_block_rlp,
 			};
 		}
@@ -1182,7 +1192,7 @@ impl BlockChain {
 				batch.put(db::COL_EXTRA, b"best", &update.info.hash);
 				*best_block = Some(BestBlock {
 					total_difficulty: update.info.total_difficulty,
-					header: update.block.decode_header(),
+					header: update.block.decode_header(self.eip1559_transition),
 					block: update.block,
 				});
 			}
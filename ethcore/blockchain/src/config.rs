@@ -16,6 +16,8 @@
 
 //! Blockchain configuration.
 
+use common_types::BlockNumber;
+
 /// Blockchain configuration.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Config {
@@ -23,6 +25,8 @@ pub struct Config {
 	pub pref_cache_size: usize,
 	/// Maximum cache size in bytes.
 	pub max_cache_size: usize,
+	/// Number of first block whose header carries a base fee (EIP-1559).
+	pub eip1559_transition: BlockNumber,
 }
 
 impl Default for Config {
@@ -30,6 +34,7 @@ impl Default for Config {
 		Config {
 			pref_cache_size: 1 << 14,
 			max_cache_size: 1 << 20,
+			eip1559_transition: BlockNumber::max_value(),
 		}
 	}
 }
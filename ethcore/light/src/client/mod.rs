@@ -323,7 +323,7 @@ impl<T: ChainDataFetcher> Client<T> {
 
 			let epoch_proof = self.engine.is_epoch_end_light(
 				&verified_header,
-				&|h| self.chain.block_header(BlockId::Hash(h)).and_then(|hdr| hdr.decode().ok()),
+				&|h| self.chain.block_header(BlockId::Hash(h)).and_then(|hdr| hdr.decode(self.engine.params().eip1559_transition).ok()),
 				&|h| self.chain.pending_transition(h),
 			);
 
@@ -441,10 +441,11 @@ impl<T: ChainDataFetcher> Client<T> {
 		// Verify Block Family
 
 		let verify_family_result = {
-			parent_header.decode()
+			parent_header.decode(self.engine.params().eip1559_transition)
 				.map_err(|dec_err| dec_err.into())
 				.and_then(|decoded| {
-					self.engine.verify_block_family(&verified_header, &decoded)
+					self.engine.verify_block_family(&verified_header, &decoded)?;
+					self.engine.verify_base_fee(&verified_header, &decoded)
 				})
 
 		};
@@ -753,14 +753,16 @@ pub mod header {
 
 	impl Decodable for Response {
 		fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+			use common_types::BlockNumber;
 			use common_types::header::Header as FullHeader;
 
 			let mut headers = Vec::new();
 
 			for item in rlp.iter() {
-				// check that it's a valid encoding.
+				// check that it's a valid encoding. the EIP-1559 transition isn't known
+				// here, so a base fee is only checked structurally, as a seal field.
 				// TODO: just return full headers here?
-				let _: FullHeader = item.as_val()?;
+				let _ = FullHeader::decode_rlp(&item, BlockNumber::max_value())?;
 				headers.push(encoded::Header::new(item.as_raw().to_owned()));
 			}
 
@@ -1089,12 +1091,14 @@ pub mod block_body {
 
 	impl Decodable for Response {
 		fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+			use common_types::BlockNumber;
 			use common_types::header::Header as FullHeader;
 			use common_types::transaction::UnverifiedTransaction;
 
 			// check body validity.
 			let _: Vec<UnverifiedTransaction> = rlp.list_at(0)?;
-			let _: Vec<FullHeader> = rlp.list_at(1)?;
+			// the EIP-1559 transition isn't known here, see `header::Response`.
+			let _ = FullHeader::decode_rlp_list(&rlp.at(1)?, BlockNumber::max_value())?;
 
 			Ok(Response {
 				body: encoded::Body::new(rlp.as_raw().to_owned()),
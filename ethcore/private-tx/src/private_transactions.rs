@@ -81,6 +81,16 @@ impl pool::ScoredTransaction for VerifiedPrivateTransaction {
 	fn nonce(&self) -> U256 {
 		self.transaction.nonce
 	}
+
+	/// Gets transaction max priority fee per gas.
+	fn max_priority_fee_per_gas(&self) -> U256 {
+		self.transaction.max_priority_fee_per_gas()
+	}
+
+	/// Gets the fee per gas paid to the block author.
+	fn effective_priority_fee(&self, base_fee: Option<U256>) -> U256 {
+		self.transaction.effective_priority_fee(base_fee)
+	}
 }
 
 /// Checks readiness of transactions by looking if the transaction from sender already exists.
@@ -133,7 +143,7 @@ impl Default for VerificationStore {
 			verification_pool: RwLock::new(
 				txpool::Pool::new(
 					txpool::NoopListener,
-					pool::scoring::NonceAndGasPrice(pool::PrioritizationStrategy::GasPriceOnly),
+					pool::scoring::NonceAndGasPrice::new(pool::PrioritizationStrategy::GasPriceOnly),
 					pool::Options {
 						max_count: MAX_QUEUE_LEN,
 						max_per_sender: MAX_QUEUE_LEN / 10,
@@ -237,6 +237,12 @@ impl<'x> OpenBlock<'x> {
 			return Err(TransactionError::AlreadyImported.into());
 		}
 
+		if let Some(base_fee) = self.block.header.base_fee_per_gas() {
+			if t.gas_price < base_fee {
+				return Err(TransactionError::GasPriceLowerThanBaseFee { base_fee, got: t.gas_price }.into());
+			}
+		}
+
 		let env_info = self.block.env_info();
 		let outcome = self.block.state.apply(&env_info, self.engine.machine(), &t, self.block.traces.is_enabled())?;
 
@@ -578,7 +584,7 @@ mod tests {
 		factories: Factories,
 	) -> Result<LockedBlock, Error> {
 
-		let block = Unverified::from_rlp(block_bytes)?;
+		let block = Unverified::from_rlp(block_bytes, engine.params().eip1559_transition)?;
 		let header = block.header;
 		let transactions: Result<Vec<_>, Error> = block
 			.transactions
@@ -630,7 +636,7 @@ mod tests {
 		last_hashes: Arc<LastHashes>,
 		factories: Factories,
 	) -> Result<SealedBlock, Error> {
-		let header = Unverified::from_rlp(block_bytes.clone())?.header;
+		let header = Unverified::from_rlp(block_bytes.clone(), engine.params().eip1559_transition)?.header;
 		Ok(enact_bytes(block_bytes, engine, tracing, db, parent, last_hashes, factories)?
 			.seal(engine, header.seal().to_vec())?)
 	}
@@ -697,11 +703,46 @@ mod tests {
 
 		let bytes = e.rlp_bytes();
 		assert_eq!(bytes, orig_bytes);
-		let uncles = view!(BlockView, &bytes).uncles();
+		let uncles = view!(BlockView, &bytes).uncles(engine.params().eip1559_transition);
 		assert_eq!(uncles[1].extra_data(), b"uncle2");
 
 		let db = e.drain().state.drop().1;
 		assert_eq!(orig_db.journal_db().keys(), db.journal_db().keys());
 		assert!(orig_db.journal_db().keys().iter().filter(|k| orig_db.journal_db().get(k.0) != db.journal_db().get(k.0)).next() == None);
 	}
+
+	#[test]
+	fn rejects_transaction_below_base_fee() {
+		use spec::*;
+		use error::ErrorKind;
+		use types::transaction::{Transaction, Action};
+		use hash::keccak;
+
+		let spec = Spec::new_null_eip1559_genesis();
+		let engine = &*spec.engine;
+		let genesis_header = spec.genesis_header();
+
+		let db = spec.ensure_db_good(get_temp_state_db(), &Default::default()).unwrap();
+		let last_hashes = Arc::new(vec![genesis_header.hash()]);
+		let mut b = OpenBlock::new(engine, Default::default(), false, db, &genesis_header, last_hashes, Address::zero(), (3141562.into(), 31415620.into()), vec![], false, None).unwrap();
+		let base_fee = b.header.base_fee_per_gas().unwrap();
+
+		let tx = |nonce: u64, gas_price: U256| Transaction {
+			nonce: nonce.into(),
+			gas_price,
+			gas: 21_000.into(),
+			action: Action::Call(Address::zero()),
+			value: 0.into(),
+			data: vec![],
+		}.sign(&keccak("").into(), None);
+
+		match b.push_transaction(tx(0, base_fee - 1), None).map(|_| ()).unwrap_err() {
+			Error(ErrorKind::Transaction(TransactionError::GasPriceLowerThanBaseFee { base_fee: fee, got }), _) => {
+				assert_eq!(fee, base_fee);
+				assert_eq!(got, base_fee - 1);
+			},
+			e => panic!("unexpected error: {:?}", e),
+		}
+		b.push_transaction(tx(0, base_fee), None).unwrap();
+	}
 }
@@ -257,8 +257,8 @@ impl Importer {
 			block_queue,
 			miner,
 			ancient_verifier: AncientVerifier::new(engine.clone()),
+			bad_blocks: bad_blocks::BadBlocks::new(engine.params().eip1559_transition),
 			engine,
-			bad_blocks: Default::default(),
 		})
 	}
 
@@ -504,7 +504,7 @@ impl Importer {
 			let hash = best_hash;
 			let header = chain.block_header_data(&hash)
 				.expect("Best block is in the database; qed")
-				.decode()
+				.decode(self.engine.params().eip1559_transition)
 				.expect("Stored block header is valid RLP; qed");
 			let details = chain.block_details(&hash)
 				.expect("Best block is in the database; qed");
@@ -700,7 +700,7 @@ impl Client {
 	/// Create a new client with given parameters.
 	/// The database is assumed to have been initialized with the correct columns.
 	pub fn new(
-		config: ClientConfig,
+		mut config: ClientConfig,
 		spec: &Spec,
 		db: Arc<BlockChainDB>,
 		miner: Arc<Miner>,
@@ -729,6 +729,7 @@ impl Client {
 		}
 
 		let gb = spec.genesis_block();
+		config.blockchain.eip1559_transition = spec.params().eip1559_transition;
 		let chain = Arc::new(BlockChain::new(config.blockchain.clone(), &gb, db.clone()));
 		let tracedb = RwLock::new(TraceDB::new(config.tracing.clone(), db.clone(), chain.clone()));
 
@@ -1297,7 +1298,7 @@ impl Client {
 				=> Some(self.chain.read().best_block_header()),
 			BlockId::Number(number) if number == self.chain.read().best_block_number()
 				=> Some(self.chain.read().best_block_header()),
-			_   => self.block_header(id).and_then(|h| h.decode().ok())
+			_   => self.block_header(id).and_then(|h| h.decode(self.engine.params().eip1559_transition).ok())
 		}
 	}
 }
@@ -2141,7 +2142,7 @@ impl BlockChainClient for Client {
 	fn uncle_extra_info(&self, id: UncleId) -> Option<BTreeMap<String, String>> {
 		self.uncle(id)
 			.and_then(|h| {
-				h.decode().map(|dh| {
+				h.decode(self.engine.params().eip1559_transition).map(|dh| {
 					self.engine.extra_info(&dh)
 				}).ok()
 			})
@@ -2297,7 +2298,7 @@ impl ReopenBlock for Client {
 			for h in uncles {
 				if !block.uncles.iter().any(|header| header.hash() == h) {
 					let uncle = chain.block_header_data(&h).expect("find_uncle_hashes only returns hashes for existing headers; qed");
-					let uncle = uncle.decode().expect("decoding failure");
+					let uncle = uncle.decode(engine.params().eip1559_transition).expect("decoding failure");
 					block.push_uncle(uncle).expect("pushing up to maximum_uncle_count;
 												push_uncle is not ok only if more than maximum_uncle_count is pushed;
 												so all push_uncle are Ok;
@@ -2340,7 +2341,7 @@ impl PrepareOpenBlock for Client {
 			.into_iter()
 			.take(engine.maximum_uncle_count(open_block.header.number()))
 			.foreach(|h| {
-				open_block.push_uncle(h.decode().expect("decoding failure")).expect("pushing maximum_uncle_count;
+				open_block.push_uncle(h.decode(engine.params().eip1559_transition).expect("decoding failure")).expect("pushing maximum_uncle_count;
 												open_block was just created;
 												push_uncle is not ok only if more than maximum_uncle_count is pushed;
 												so all push_uncle are Ok;
@@ -311,7 +311,7 @@ impl TestBlockChainClient {
 		rlp.append(&header);
 		rlp.append_raw(&txs, 1);
 		rlp.append_raw(uncles.as_raw(), 1);
-		let unverified = Unverified::from_rlp(rlp.out()).unwrap();
+		let unverified = Unverified::from_rlp(rlp.out(), self.spec.params().eip1559_transition).unwrap();
 		self.import_block(unverified).unwrap();
 	}
 
@@ -325,7 +325,7 @@ impl TestBlockChainClient {
 	/// Make a bad block by setting invalid parent hash.
 	pub fn corrupt_block_parent(&self, n: BlockNumber) {
 		let hash = self.block_hash(BlockId::Number(n)).unwrap();
-		let mut header: Header = self.block_header(BlockId::Number(n)).unwrap().decode().expect("decoding failed");
+		let mut header: Header = self.block_header(BlockId::Number(n)).unwrap().decode(self.spec.params().eip1559_transition).expect("decoding failed");
 		header.set_parent_hash(H256::from(42));
 		let mut rlp = RlpStream::new_list(3);
 		rlp.append(&header);
@@ -503,7 +503,7 @@ impl BlockInfo for TestBlockChainClient {
 	fn best_block_header(&self) -> Header {
 		self.block_header(BlockId::Hash(self.chain_info().best_block_hash))
 			.expect("Best block always has header.")
-			.decode()
+			.decode(self.spec.params().eip1559_transition)
 			.expect("decoding failed")
 	}
 
@@ -548,7 +548,7 @@ impl ImportBlock for TestBlockChainClient {
 		if number > 0 {
 			match self.blocks.read().get(header.parent_hash()) {
 				Some(parent) => {
-					let parent = view!(BlockView, parent).header();
+					let parent = view!(BlockView, parent).header_view();
 					if parent.number() != (header.number() - 1) {
 						panic!("Unexpected block parent");
 					}
@@ -573,7 +573,7 @@ impl ImportBlock for TestBlockChainClient {
 				while n > 0 && self.numbers.read()[&n] != parent_hash {
 					*self.numbers.write().get_mut(&n).unwrap() = parent_hash.clone();
 					n -= 1;
-					parent_hash = view!(BlockView, &self.blocks.read()[&parent_hash]).header().parent_hash().clone();
+					parent_hash = view!(BlockView, &self.blocks.read()[&parent_hash]).header_view().parent_hash();
 				}
 			}
 		}
@@ -757,7 +757,7 @@ impl BlockChainClient for TestBlockChainClient {
 
 	fn block_extra_info(&self, id: BlockId) -> Option<BTreeMap<String, String>> {
 		self.block(id)
-			.map(|block| block.view().header())
+			.map(|block| block.view().header(self.spec.params().eip1559_transition))
 			.map(|header| self.spec.engine.extra_info(&header))
 	}
 
@@ -340,7 +340,7 @@ pub trait BlockChainClient : Sync + Send + AccountData + BlockChain + CallContra
 				if block.number() == 0 {
 					return corpus.into();
 				}
-				block.transaction_views().iter().foreach(|t| corpus.push(t.gas_price()));
+				block.transactions().iter().foreach(|t| corpus.push(t.gas_price));
 				h = block.parent_hash().clone();
 			}
 		}
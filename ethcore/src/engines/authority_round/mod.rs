@@ -437,6 +437,7 @@ struct EpochVerifier {
 	step: Arc<PermissionedStep>,
 	subchain_validators: SimpleList,
 	empty_steps_transition: u64,
+	eip1559_transition: BlockNumber,
 }
 
 impl super::EpochVerifier<EthereumMachine> for EpochVerifier {
@@ -452,7 +453,7 @@ impl super::EpochVerifier<EthereumMachine> for EpochVerifier {
 		let mut finality_checker = RollingFinality::blank(self.subchain_validators.clone().into_inner());
 		let mut finalized = Vec::new();
 
-		let headers: Vec<Header> = Rlp::new(proof).as_list().ok()?;
+		let headers = Header::decode_rlp_list(&Rlp::new(proof), self.eip1559_transition).ok()?;
 
 		{
 			let mut push_header = |parent_header: &Header, header: Option<&Header>| {
@@ -1190,7 +1191,7 @@ impl Engine<EthereumMachine> for AuthorityRound {
 
 				let parent = client.block_header(::client::BlockId::Hash(*block.header.parent_hash()))
 					.expect("hash is from parent; parent header must exist; qed")
-					.decode()?;
+					.decode(self.machine.params().eip1559_transition)?;
 
 				let parent_step = header_step(&parent, self.empty_steps_transition)?;
 				let current_step = self.step.inner.load();
@@ -1496,6 +1497,7 @@ impl Engine<EthereumMachine> for AuthorityRound {
 					step: self.step.clone(),
 					subchain_validators: list,
 					empty_steps_transition: self.empty_steps_transition,
+					eip1559_transition: self.machine.params().eip1559_transition,
 				});
 
 				match finalize {
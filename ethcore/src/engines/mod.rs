@@ -486,6 +486,16 @@ pub trait EthEngine: Engine<::machine::EthereumMachine> {
 		self.machine().params()
 	}
 
+	/// The EIP-1559 base fee of a block with the given number on top of `parent`.
+	fn expected_base_fee(&self, number: BlockNumber, parent: &Header) -> Option<U256> {
+		self.machine().expected_base_fee(number, parent)
+	}
+
+	/// Verify that the header carries the EIP-1559 base fee implied by its parent.
+	fn verify_base_fee(&self, header: &Header, parent: &Header) -> Result<(), Error> {
+		self.machine().verify_base_fee(header, parent)
+	}
+
 	/// Get the EVM schedule for the given block number.
 	fn schedule(&self, block_number: BlockNumber) -> Schedule {
 		self.machine().schedule(block_number)
@@ -26,6 +26,7 @@ use kvdb::DBValue;
 use memory_cache::MemoryLruCache;
 use parking_lot::RwLock;
 use rlp::{Rlp, RlpStream};
+use types::BlockNumber;
 use types::header::Header;
 use types::ids::BlockId;
 use types::log_entry::LogEntry;
@@ -61,7 +62,7 @@ impl ::engines::StateDependentProof<EthereumMachine> for StateProof {
 	}
 
 	fn check_proof(&self, machine: &EthereumMachine, proof: &[u8]) -> Result<(), String> {
-		let (header, state_items) = decode_first_proof(&Rlp::new(proof))
+		let (header, state_items) = decode_first_proof(&Rlp::new(proof), machine.params().eip1559_transition)
 			.map_err(|e| format!("proof incorrectly encoded: {}", e))?;
 		if &header != &self.header {
 			return Err("wrong header in proof".into());
@@ -142,8 +143,8 @@ fn check_first_proof(machine: &EthereumMachine, contract_address: Address, old_h
 	}
 }
 
-fn decode_first_proof(rlp: &Rlp) -> Result<(Header, Vec<DBValue>), ::error::Error> {
-	let header = rlp.val_at(0)?;
+fn decode_first_proof(rlp: &Rlp, eip1559_transition: BlockNumber) -> Result<(Header, Vec<DBValue>), ::error::Error> {
+	let header = Header::decode_rlp(&rlp.at(0)?, eip1559_transition)?;
 	let state_items = rlp.at(1)?.iter().map(|x| {
 		let mut val = DBValue::new();
 		val.append_slice(x.data()?);
@@ -162,8 +163,8 @@ fn encode_proof(header: &Header, receipts: &[Receipt]) -> Bytes {
 	stream.drain()
 }
 
-fn decode_proof(rlp: &Rlp) -> Result<(Header, Vec<Receipt>), ::error::Error> {
-	Ok((rlp.val_at(0)?, rlp.list_at(1)?))
+fn decode_proof(rlp: &Rlp, eip1559_transition: BlockNumber) -> Result<(Header, Vec<Receipt>), ::error::Error> {
+	Ok((Header::decode_rlp(&rlp.at(0)?, eip1559_transition)?, rlp.list_at(1)?))
 }
 
 // given a provider and caller, generate proof. this will just be a state proof
@@ -353,7 +354,7 @@ impl ValidatorSet for ValidatorSafeContract {
 		if first {
 			trace!(target: "engine", "Recovering initial epoch set");
 
-			let (old_header, state_items) = decode_first_proof(&rlp)?;
+			let (old_header, state_items) = decode_first_proof(&rlp, machine.params().eip1559_transition)?;
 			let number = old_header.number();
 			let old_hash = old_header.hash();
 			let addresses = check_first_proof(machine, self.contract_address, old_header, &state_items)
@@ -364,7 +365,7 @@ impl ValidatorSet for ValidatorSafeContract {
 
 			Ok((SimpleList::new(addresses), Some(old_hash)))
 		} else {
-			let (old_header, receipts) = decode_proof(&rlp)?;
+			let (old_header, receipts) = decode_proof(&rlp, machine.params().eip1559_transition)?;
 
 			// ensure receipts match header.
 			// TODO: optimize? these were just decoded.
@@ -529,7 +530,8 @@ mod tests {
 		let sync_client = generate_dummy_client_with_spec_and_data(Spec::new_validator_safe_contract, 0, 0, &[]);
 		sync_client.engine().register_client(Arc::downgrade(&sync_client) as _);
 		for i in 1..4 {
-			sync_client.import_block(Unverified::from_rlp(client.block(BlockId::Number(i)).unwrap().into_inner()).unwrap()).unwrap();
+			let eip1559_transition = sync_client.engine().params().eip1559_transition;
+			sync_client.import_block(Unverified::from_rlp(client.block(BlockId::Number(i)).unwrap().into_inner(), eip1559_transition).unwrap()).unwrap();
 		}
 		sync_client.flush_queue();
 		assert_eq!(sync_client.chain_info().best_block_number, 3);
@@ -64,6 +64,8 @@ pub enum BlockError {
 	InvalidStateRoot(Mismatch<H256>),
 	/// Gas used header field is invalid.
 	InvalidGasUsed(Mismatch<U256>),
+	/// Base fee header field is invalid or present before the EIP-1559 transition.
+	InvalidBaseFee(Mismatch<Option<U256>>),
 	/// Transactions root header field is invalid.
 	InvalidTransactionsRoot(Mismatch<H256>),
 	/// Difficulty is out of range; this can be used as an looser error prior to getting a definitive
@@ -122,6 +124,7 @@ impl fmt::Display for BlockError {
 			UncleParentNotInChain(ref hash) => format!("Uncle {} has a parent not in the chain", hash),
 			InvalidStateRoot(ref mis) => format!("Invalid state root in header: {}", mis),
 			InvalidGasUsed(ref mis) => format!("Invalid gas used in header: {}", mis),
+			InvalidBaseFee(ref mis) => format!("Invalid base fee in header: expected {:?}, found {:?}", mis.expected, mis.found),
 			InvalidTransactionsRoot(ref mis) => format!("Invalid transactions root in header: {}", mis),
 			DifficultyOutOfBounds(ref oob) => format!("Invalid block difficulty: {}", oob),
 			InvalidDifficulty(ref mis) => format!("Invalid block difficulty: {}", mis),
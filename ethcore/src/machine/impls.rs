@@ -20,9 +20,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::cmp;
 use std::sync::Arc;
 
-use ethereum_types::{U256, H256, Address};
+use ethereum_types::{U256, U512, H256, Address};
 use rlp::Rlp;
-use types::transaction::{self, SYSTEM_ADDRESS, UNSIGNED_SENDER, UnverifiedTransaction, SignedTransaction};
+use types::transaction::{self, SYSTEM_ADDRESS, UNSIGNED_SENDER, UnverifiedTransaction, SignedTransaction, TypedTxId};
 use types::BlockNumber;
 use types::header::Header;
 use vm::{CallType, ActionParams, ActionValue, ParamsType};
@@ -32,16 +32,26 @@ use block::ExecutedBlock;
 use builtin::Builtin;
 use call_contract::CallContract;
 use client::BlockInfo;
-use error::Error;
+use error::{Error, BlockError};
 use executive::Executive;
 use spec::CommonParams;
 use state::{CleanupMode, Substate};
 use trace::{NoopTracer, NoopVMTracer};
 use tx_filter::TransactionFilter;
+use unexpected::Mismatch;
 
 /// Parity tries to round block.gas_limit to multiple of this constant
 pub const PARITY_GAS_LIMIT_DETERMINANT: U256 = U256([37, 0, 0, 0]);
 
+/// Base fee of the first block after the EIP-1559 transition.
+pub const EIP1559_INITIAL_BASE_FEE: u64 = 1_000_000_000;
+
+/// Bounds the base fee change between two blocks to `1 / EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR`.
+pub const EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Ratio between the block gas limit and the gas usage the base fee targets.
+pub const EIP1559_ELASTICITY_MULTIPLIER: u64 = 2;
+
 /// Ethash-specific extensions.
 #[derive(Debug, Clone)]
 pub struct EthashExtensions {
@@ -228,11 +238,37 @@ impl EthereumMachine {
 		Ok(())
 	}
 
+	/// The base fee of a header with the given number on top of `parent`, or `None`
+	/// before the EIP-1559 transition.
+	pub fn expected_base_fee(&self, number: BlockNumber, parent: &Header) -> Option<U256> {
+		if number < self.params().eip1559_transition {
+			return None;
+		}
+
+		Some(match parent.base_fee_per_gas() {
+			Some(parent_base_fee) => compute_base_fee(*parent.gas_used(), *parent.gas_limit(), parent_base_fee),
+			None => EIP1559_INITIAL_BASE_FEE.into(),
+		})
+	}
+
+	/// Verify that the header carries the base fee implied by its parent.
+	pub fn verify_base_fee(&self, header: &Header, parent: &Header) -> Result<(), Error> {
+		let expected = self.expected_base_fee(header.number(), parent);
+		if header.base_fee_per_gas() != expected {
+			return Err(BlockError::InvalidBaseFee(Mismatch { expected, found: header.base_fee_per_gas() }).into());
+		}
+		Ok(())
+	}
+
 	/// Populate a header's fields based on its parent's header.
 	/// Usually implements the chain scoring rule based on weight.
 	/// The gas floor target must not be lower than the engine's minimum gas limit.
 	pub fn populate_from_parent(&self, header: &mut Header, parent: &Header, gas_floor_target: U256, gas_ceil_target: U256) {
 		header.set_difficulty(parent.difficulty().clone());
+
+		let base_fee = self.expected_base_fee(header.number(), parent);
+		header.set_base_fee_per_gas(base_fee);
+
 		let gas_limit = parent.gas_limit().clone();
 		assert!(!gas_limit.is_zero(), "Gas limit should be > 0");
 
@@ -353,14 +389,21 @@ impl EthereumMachine {
 		Ok(SignedTransaction::new(t)?)
 	}
 
-	/// Does basic verification of the transaction.
+	/// Does basic verification of the transaction. Typed transactions are only valid
+	/// once their type is enabled and always have to name this chain's ID.
 	pub fn verify_transaction_basic(&self, t: &UnverifiedTransaction, header: &Header) -> Result<(), transaction::Error> {
+		if !self.params().is_transaction_type_enabled(t.tx_type(), header.number()) {
+			return Err(transaction::Error::TransactionTypeNotEnabled(t.tx_type()));
+		}
+
 		let check_low_s = match self.ethash_extensions {
 			Some(ref ext) => header.number() >= ext.homestead_transition,
 			None => true,
 		};
 
-		let chain_id = if header.number() < self.params().validate_chain_id_transition {
+		let chain_id = if t.tx_type() != TypedTxId::Legacy {
+			Some(self.params().chain_id)
+		} else if header.number() < self.params().validate_chain_id_transition {
 			t.chain_id()
 		} else if header.number() >= self.params().eip155_transition {
 			Some(self.params().chain_id)
@@ -459,6 +502,39 @@ fn round_block_gas_limit(gas_limit: U256, lower_limit: U256, upper_limit: U256)
 	}
 }
 
+/// Compute the EIP-1559 base fee of a block from its parent.
+///
+/// The base fee moves by at most 12.5% per block, up when the parent used more than half
+/// of its gas limit and down when it used less. Saturates at `U256::max_value()`.
+pub fn compute_base_fee(parent_gas_used: U256, parent_gas_limit: U256, parent_base_fee: U256) -> U256 {
+	let gas_target = parent_gas_limit / U256::from(EIP1559_ELASTICITY_MULTIPLIER);
+	if gas_target.is_zero() || parent_gas_used == gas_target {
+		return parent_base_fee;
+	}
+
+	// `parent_base_fee * gas_delta` may not fit in 256 bits.
+	let base_fee_delta = |gas_delta: U256| saturating_u256(
+		parent_base_fee.full_mul(gas_delta) / U512::from(gas_target) / U512::from(EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR)
+	);
+
+	if parent_gas_used > gas_target {
+		let base_fee_delta = cmp::max(base_fee_delta(parent_gas_used - gas_target), U256::one());
+		parent_base_fee.saturating_add(base_fee_delta)
+	} else {
+		parent_base_fee.saturating_sub(base_fee_delta(gas_target - parent_gas_used))
+	}
+}
+
+// Convert to `U256`, saturating at `U256::max_value()`.
+fn saturating_u256(value: U512) -> U256 {
+	let U512(parts) = value;
+	if parts[4] | parts[5] | parts[6] | parts[7] != 0 {
+		U256::max_value()
+	} else {
+		U256([parts[0], parts[1], parts[2], parts[3]])
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -491,6 +567,44 @@ mod tests {
 		assert_eq!(res, Err(transaction::Error::InvalidSignature("Crypto error (Invalid EC signature)".into())));
 	}
 
+	#[test]
+	fn typed_transactions_need_their_transition() {
+		use ethkey::{Random, Generator};
+		use types::transaction::{AccessListTransaction, EIP1559Transaction};
+
+		let spec = ::ethereum::new_homestead_test();
+		let mut params = spec.params().clone();
+		params.eip2930_transition = 10;
+		params.eip1559_transition = 20;
+		let chain_id = params.chain_id;
+		let machine = EthereumMachine::regular(params, Default::default());
+
+		let keypair = Random.generate().unwrap();
+		let access_list = AccessListTransaction {
+			chain_id,
+			gas: 21_000.into(),
+			..Default::default()
+		}.sign(keypair.secret());
+		let eip1559 = EIP1559Transaction {
+			chain_id,
+			gas: 21_000.into(),
+			..Default::default()
+		}.sign(keypair.secret());
+		let mut header = ::types::header::Header::new();
+
+		header.set_number(9);
+		assert_eq!(machine.verify_transaction_basic(&access_list, &header),
+			Err(transaction::Error::TransactionTypeNotEnabled(TypedTxId::AccessList)));
+
+		header.set_number(10);
+		assert_eq!(machine.verify_transaction_basic(&access_list, &header), Ok(()));
+		assert_eq!(machine.verify_transaction_basic(&eip1559, &header),
+			Err(transaction::Error::TransactionTypeNotEnabled(TypedTxId::EIP1559)));
+
+		header.set_number(20);
+		assert_eq!(machine.verify_transaction_basic(&eip1559, &header), Ok(()));
+	}
+
 	#[test]
 	fn ethash_gas_limit_is_multiple_of_determinant() {
 		use ethereum_types::U256;
@@ -542,4 +656,137 @@ mod tests {
 		machine.populate_from_parent(&mut header, &parent, U256::from(150_000), U256::from(150_002));
 		assert_eq!(*header.gas_limit(), U256::from(150_002));
 	}
+
+	#[test]
+	fn base_fee_follows_parent_gas_usage() {
+		let base_fee = U256::from(EIP1559_INITIAL_BASE_FEE);
+
+		// at target
+		assert_eq!(compute_base_fee(5_000_000.into(), 10_000_000.into(), base_fee), base_fee);
+		// full block raises the fee by 12.5%
+		assert_eq!(compute_base_fee(10_000_000.into(), 10_000_000.into(), base_fee), U256::from(1_125_000_000));
+		// empty block lowers the fee by 12.5%
+		assert_eq!(compute_base_fee(0.into(), 10_000_000.into(), base_fee), U256::from(875_000_000));
+		// slightly above target still raises the fee
+		assert_eq!(compute_base_fee(5_000_001.into(), 10_000_000.into(), U256::from(1)), U256::from(2));
+		// zero gas limit keeps the fee
+		assert_eq!(compute_base_fee(0.into(), 0.into(), base_fee), base_fee);
+	}
+
+	#[test]
+	fn base_fee_saturates_instead_of_overflowing() {
+		let max = U256::max_value();
+
+		assert_eq!(compute_base_fee(max, 2.into(), max), max);
+		assert_eq!(compute_base_fee(max, max, max), max);
+		assert_eq!(compute_base_fee(0.into(), max, max), max - max / U256::from(EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR));
+	}
+
+	#[test]
+	fn base_fee_change_is_bounded() {
+		let gas_limit = 10_000_000u64;
+		for base_fee in &[1u64, 7, 1_000, EIP1559_INITIAL_BASE_FEE, u64::max_value()] {
+			let base_fee = U256::from(*base_fee);
+			let max_delta = cmp::max(base_fee / U256::from(EIP1559_BASE_FEE_MAX_CHANGE_DENOMINATOR), U256::one());
+			for gas_used in (0..=gas_limit).step_by(250_000) {
+				let next = compute_base_fee(gas_used.into(), gas_limit.into(), base_fee);
+				let delta = if next > base_fee { next - base_fee } else { base_fee - next };
+				assert!(delta <= max_delta);
+				assert_eq!(next > base_fee, gas_used > gas_limit / 2);
+			}
+		}
+	}
+
+	#[test]
+	fn base_fee_converges_to_target_gas_usage() {
+		let gas_limit = 10_000_000u64;
+		let gas_target = gas_limit / EIP1559_ELASTICITY_MULTIPLIER;
+		// Demand curve: the higher the fee, the less gas users are willing to spend.
+		let gas_used_at = |base_fee: U256| -> u64 {
+			let equilibrium = U256::from(50_000_000_000u64);
+			if base_fee >= equilibrium * 2 {
+				0
+			} else {
+				gas_limit - (U256::from(gas_limit) * base_fee / (equilibrium * 2)).as_u64()
+			}
+		};
+
+		for start in &[EIP1559_INITIAL_BASE_FEE, 10_000_000_000, 90_000_000_000] {
+			let mut base_fee = U256::from(*start);
+			let mut gas_used = gas_used_at(base_fee);
+			for _ in 0..100 {
+				base_fee = compute_base_fee(gas_used.into(), gas_limit.into(), base_fee);
+				gas_used = gas_used_at(base_fee);
+			}
+			let off_target = if gas_used > gas_target { gas_used - gas_target } else { gas_target - gas_used };
+			assert!(off_target < gas_target / 100, "start {}: gas used {} too far from target", start, gas_used);
+		}
+	}
+
+	#[test]
+	fn populates_base_fee_after_transition() {
+		let spec = ::ethereum::new_homestead_test();
+		let mut params = spec.params().clone();
+		params.eip1559_transition = 2;
+		let machine = EthereumMachine::regular(params, Default::default());
+
+		let mut parent = ::types::header::Header::new();
+		parent.set_number(1);
+		parent.set_gas_limit(U256::from(10_000_000));
+		let mut header = ::types::header::Header::new();
+
+		header.set_number(1);
+		machine.populate_from_parent(&mut header, &parent, U256::from(100_000), U256::from(200_000));
+		assert_eq!(header.base_fee_per_gas(), None);
+
+		header.set_number(2);
+		machine.populate_from_parent(&mut header, &parent, U256::from(100_000), U256::from(200_000));
+		assert_eq!(header.base_fee_per_gas(), Some(U256::from(EIP1559_INITIAL_BASE_FEE)));
+
+		parent.set_number(2);
+		parent.set_base_fee_per_gas(Some(U256::from(EIP1559_INITIAL_BASE_FEE)));
+		parent.set_gas_used(U256::from(10_000_000));
+		header.set_number(3);
+		machine.populate_from_parent(&mut header, &parent, U256::from(100_000), U256::from(200_000));
+		assert_eq!(header.base_fee_per_gas(), Some(U256::from(1_125_000_000)));
+
+		// gas values wider than 64 bits are not truncated.
+		let wide_gas_limit = U256::from(u64::max_value()) + U256::one();
+		parent.set_gas_limit(wide_gas_limit);
+		parent.set_gas_used(wide_gas_limit);
+		machine.populate_from_parent(&mut header, &parent, U256::from(100_000), U256::from(200_000));
+		assert_eq!(header.base_fee_per_gas(), Some(U256::from(1_125_000_000)));
+	}
+
+	#[test]
+	fn verifies_base_fee_against_parent() {
+		let spec = ::ethereum::new_homestead_test();
+		let mut params = spec.params().clone();
+		params.eip1559_transition = 2;
+		let machine = EthereumMachine::regular(params, Default::default());
+
+		let mut parent = ::types::header::Header::new();
+		parent.set_number(1);
+		parent.set_gas_limit(U256::from(10_000_000));
+		let mut header = ::types::header::Header::new();
+		header.set_number(2);
+
+		assert!(machine.verify_base_fee(&header, &parent).is_err());
+		header.set_base_fee_per_gas(Some(U256::from(EIP1559_INITIAL_BASE_FEE)));
+		assert!(machine.verify_base_fee(&header, &parent).is_ok());
+		header.set_base_fee_per_gas(Some(U256::from(EIP1559_INITIAL_BASE_FEE + 1)));
+		match *machine.verify_base_fee(&header, &parent).unwrap_err().kind() {
+			::error::ErrorKind::Block(BlockError::InvalidBaseFee(ref mismatch)) => {
+				assert_eq!(mismatch.expected, Some(U256::from(EIP1559_INITIAL_BASE_FEE)));
+				assert_eq!(mismatch.found, Some(U256::from(EIP1559_INITIAL_BASE_FEE + 1)));
+			},
+			ref e => panic!("unexpected error: {:?}", e),
+		}
+
+		// headers before the transition must not carry a base fee.
+		header.set_number(1);
+		assert!(machine.verify_base_fee(&header, &parent).is_err());
+		header.set_base_fee_per_gas(None);
+		assert!(machine.verify_base_fee(&header, &parent).is_ok());
+	}
 }
@@ -551,6 +551,10 @@ impl Miner {
 				},
 				// already have transaction - ignore
 				Err(Error(ErrorKind::Transaction(transaction::Error::AlreadyImported), _)) => {},
+				// Transaction doesn't cover the block's base fee (EIP-1559), but it might once the fee drops.
+				Err(Error(ErrorKind::Transaction(transaction::Error::GasPriceLowerThanBaseFee { base_fee, got }), _)) => {
+					debug!(target: "miner", "Skipping adding transaction to block because of base fee: {:?} (base fee: {:?}, gas price: {:?})", hash, base_fee, got);
+				},
 				Err(Error(ErrorKind::Transaction(transaction::Error::NotAllowed), _)) => {
 					not_allowed_transactions.insert(hash);
 					debug!(target: "miner", "Skipping non-allowed transaction for sender {:?}", hash);
@@ -671,7 +675,7 @@ impl Miner {
 
 		let parent_header = match chain.block_header(BlockId::Hash(*block.header.parent_hash())) {
 			Some(h) => {
-				match h.decode() {
+				match h.decode(self.engine.params().eip1559_transition) {
 					Ok(decoded_hdr) => decoded_hdr,
 					Err(_) => return false
 				}
@@ -1254,8 +1258,12 @@ impl miner::MinerService for Miner {
 		}
 
 		// First update gas limit in transaction queue and minimal gas price.
-		let gas_limit = *chain.best_block_header().gas_limit();
-		self.update_transaction_queue_limits(gas_limit);
+		let best_header = chain.best_block_header();
+		self.update_transaction_queue_limits(*best_header.gas_limit());
+
+		// Rescore pooled transactions by their priority fee over the next block's base fee.
+		let base_fee = self.engine.expected_base_fee(best_header.number() + 1, &best_header);
+		self.transaction_queue.set_block_base_fee(base_fee);
 
 		// Then import all transactions from retracted blocks.
 		let client = self.pool_client(chain);
@@ -24,6 +24,7 @@ use triehash::ordered_trie_root;
 use types::block::Block;
 use types::header::Header;
 use types::views::BlockView;
+use types::BlockNumber;
 
 const HEADER_FIELDS: usize = 8;
 const BLOCK_FIELDS: usize = 2;
@@ -47,15 +48,20 @@ impl AbridgedBlock {
 
 	/// Given a full block view, trim out the parent hash and block number,
 	/// producing new rlp.
-	pub fn from_block_view(block_view: &BlockView) -> Self {
+	///
+	/// Blocks at or after `eip1559_transition` keep their base fee as the last item,
+	/// after the seal fields.
+	pub fn from_block_view(block_view: &BlockView, eip1559_transition: BlockNumber) -> Self {
 		let header = block_view.header_view();
-		let seal_fields = header.seal();
+		let seal_fields = header.seal(eip1559_transition);
+		let base_fee_fields = if header.number() >= eip1559_transition { 1 } else { 0 };
 
-		// 10 header fields, unknown number of seal fields, and 2 block fields.
+		// 10 header fields, unknown number of seal fields, 2 block fields and the optional base fee.
 		let mut stream = RlpStream::new_list(
 			HEADER_FIELDS +
 			seal_fields.len() +
-			BLOCK_FIELDS
+			BLOCK_FIELDS +
+			base_fee_fields
 		);
 
 		// write header values.
@@ -72,13 +78,19 @@ impl AbridgedBlock {
 		// write block values.
 		stream
 			.append_list(&block_view.transactions())
-			.append_list(&block_view.uncles());
+			.append_list(&block_view.uncles(eip1559_transition));
 
 		// write seal fields.
 		for field in seal_fields {
 			stream.append_raw(&field, 1);
 		}
 
+		// write base fee.
+		if base_fee_fields != 0 {
+			let header_rlp = header.rlp();
+			stream.append_raw(header_rlp.at(header_rlp.item_count() - 1).as_raw(), 1);
+		}
+
 		AbridgedBlock {
 			rlp: stream.out(),
 		}
@@ -87,7 +99,7 @@ impl AbridgedBlock {
 	/// Flesh out an abridged block view with the provided parent hash and block number.
 	///
 	/// Will fail if contains invalid rlp.
-	pub fn to_block(&self, parent_hash: H256, number: u64, receipts_root: H256) -> Result<Block, DecoderError> {
+	pub fn to_block(&self, parent_hash: H256, number: u64, receipts_root: H256, eip1559_transition: BlockNumber) -> Result<Block, DecoderError> {
 		let rlp = Rlp::new(&self.rlp);
 
 		let mut header: Header = Default::default();
@@ -103,7 +115,7 @@ impl AbridgedBlock {
 		header.set_extra_data(rlp.val_at(7)?);
 
 		let transactions = rlp.list_at(8)?;
-		let uncles: Vec<Header> = rlp.list_at(9)?;
+		let uncles = Header::decode_rlp_list(&rlp.at(9)?, eip1559_transition)?;
 
 		header.set_transactions_root(ordered_trie_root(
 			rlp.at(8)?.iter().map(|r| r.as_raw())
@@ -114,8 +126,17 @@ impl AbridgedBlock {
 		uncles_rlp.append_list(&uncles);
 		header.set_uncles_hash(keccak(uncles_rlp.as_raw()));
 
+		let mut seal_end = rlp.item_count()?;
+		if number >= eip1559_transition {
+			if seal_end <= HEADER_FIELDS + BLOCK_FIELDS {
+				return Err(DecoderError::RlpIncorrectListLen);
+			}
+			seal_end -= 1;
+			header.set_base_fee_per_gas(Some(rlp.val_at(seal_end)?));
+		}
+
 		let mut seal_fields = Vec::new();
-		for i in (HEADER_FIELDS + BLOCK_FIELDS)..rlp.item_count()? {
+		for i in (HEADER_FIELDS + BLOCK_FIELDS)..seal_end {
 			let seal_rlp = rlp.at(i)?;
 			seal_fields.push(seal_rlp.as_raw().to_owned());
 		}
@@ -140,6 +161,7 @@ mod tests {
 	use types::block::Block;
 	use types::view;
 	use types::views::BlockView;
+	use types::BlockNumber;
 
 	fn encode_block(b: &Block) -> Bytes {
 		b.rlp_bytes()
@@ -151,8 +173,8 @@ mod tests {
 		let receipts_root = b.header.receipts_root().clone();
 		let encoded = encode_block(&b);
 
-		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded));
-		assert_eq!(abridged.to_block(H256::new(), 0, receipts_root).unwrap(), b);
+		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded), BlockNumber::max_value());
+		assert_eq!(abridged.to_block(H256::new(), 0, receipts_root, BlockNumber::max_value()).unwrap(), b);
 	}
 
 	#[test]
@@ -162,8 +184,8 @@ mod tests {
 		let receipts_root = b.header.receipts_root().clone();
 		let encoded = encode_block(&b);
 
-		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded));
-		assert_eq!(abridged.to_block(H256::new(), 2, receipts_root).unwrap(), b);
+		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded), BlockNumber::max_value());
+		assert_eq!(abridged.to_block(H256::new(), 2, receipts_root, BlockNumber::max_value()).unwrap(), b);
 	}
 
 	#[test]
@@ -198,7 +220,20 @@ mod tests {
 
 		let encoded = encode_block(&b);
 
-		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded[..]));
-		assert_eq!(abridged.to_block(H256::new(), 0, receipts_root).unwrap(), b);
+		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded[..]), BlockNumber::max_value());
+		assert_eq!(abridged.to_block(H256::new(), 0, receipts_root, BlockNumber::max_value()).unwrap(), b);
+	}
+	#[test]
+	fn with_base_fee() {
+		let mut b = Block::default();
+		b.header.set_number(5);
+		b.header.set_seal(vec![::rlp::encode(&H256::from(1)), ::rlp::encode(&0u64)]);
+		b.header.set_base_fee_per_gas(Some(U256::from(1_000_000_000)));
+
+		let receipts_root = b.header.receipts_root().clone();
+		let encoded = encode_block(&b);
+
+		let abridged = AbridgedBlock::from_block_view(&view!(BlockView, &encoded), 5);
+		assert_eq!(abridged.to_block(H256::new(), 5, receipts_root, 5).unwrap(), b);
 	}
 }
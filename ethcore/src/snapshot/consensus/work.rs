@@ -118,7 +118,7 @@ impl<'a> PowWorker<'a> {
 				.and_then(|b| self.chain.block_receipts(&self.current_hash).map(|r| (b, r)))
 				.ok_or_else(|| Error::BlockNotFound(self.current_hash))?;
 
-			let abridged_rlp = AbridgedBlock::from_block_view(&block.view()).into_inner();
+			let abridged_rlp = AbridgedBlock::from_block_view(&block.view(), self.chain.eip1559_transition()).into_inner();
 
 			let pair = {
 				let mut pair_stream = RlpStream::new_list(2);
@@ -253,7 +253,7 @@ impl Rebuilder for PowRebuilder {
 			let receipts: Vec<::types::receipt::Receipt> = pair.list_at(1)?;
 			let receipts_root = ordered_trie_root(pair.at(1)?.iter().map(|r| r.as_raw()));
 
-			let block = abridged_block.to_block(parent_hash, cur_number, receipts_root)?;
+			let block = abridged_block.to_block(parent_hash, cur_number, receipts_root, engine.params().eip1559_transition)?;
 			let block_bytes = encoded::Block::new(block.rlp_bytes());
 			let is_best = cur_number == self.best_number;
 
@@ -27,7 +27,7 @@ use std::cmp;
 use super::{ManifestData, StateRebuilder, Rebuilder, RestorationStatus, SnapshotService, MAX_CHUNK_SIZE};
 use super::io::{SnapshotReader, LooseReader, SnapshotWriter, LooseWriter};
 
-use blockchain::{BlockChain, BlockChainDB, BlockChainDBHandler};
+use blockchain::{BlockChain, BlockChainDB, BlockChainDBHandler, Config as BlockChainConfig};
 use client::{BlockInfo, BlockChainClient, Client, ChainInfo, ClientIoMessage};
 use engines::EthEngine;
 use error::{Error, ErrorKind as SnapshotErrorKind};
@@ -104,7 +104,11 @@ impl Restoration {
 
 		let raw_db = params.db;
 
-		let chain = BlockChain::new(Default::default(), params.genesis, raw_db.clone());
+		let chain_config = BlockChainConfig {
+			eip1559_transition: params.engine.params().eip1559_transition,
+			..Default::default()
+		};
+		let chain = BlockChain::new(chain_config, params.genesis, raw_db.clone());
 		let components = params.engine.snapshot_components()
 			.ok_or_else(|| ::snapshot::Error::SnapshotsUnsupported)?;
 
@@ -356,7 +360,11 @@ impl Service {
 		let cur_chain_info = self.client.chain_info();
 
 		let next_db = self.restoration_db_handler.open(&rest_db)?;
-		let next_chain = BlockChain::new(Default::default(), &[], next_db.clone());
+		let chain_config = BlockChainConfig {
+			eip1559_transition: self.engine.params().eip1559_transition,
+			..Default::default()
+		};
+		let next_chain = BlockChain::new(chain_config, &[], next_db.clone());
 		let next_chain_info = next_chain.chain_info();
 
 		// The old database looks like this:
@@ -31,6 +31,7 @@ use rustc_hex::{FromHex, ToHex};
 use types::BlockNumber;
 use types::encoded;
 use types::header::Header;
+use types::transaction::TypedTxId;
 use vm::{EnvInfo, CallType, ActionValue, ActionParams, ParamsType};
 
 use builtin::Builtin;
@@ -41,7 +42,7 @@ use engines::{
 use error::Error;
 use executive::Executive;
 use factory::Factories;
-use machine::EthereumMachine;
+use machine::{EthereumMachine, EIP1559_INITIAL_BASE_FEE};
 use pod_state::PodState;
 use spec::Genesis;
 use spec::seal::Generic as GenericSeal;
@@ -123,6 +124,10 @@ pub struct CommonParams {
 	pub eip1283_disable_transition: BlockNumber,
 	/// Number of first block where EIP-1014 rules begin.
 	pub eip1014_transition: BlockNumber,
+	/// Number of first block where EIP-2718 typed transactions and EIP-2930 access list transactions are allowed.
+	pub eip2930_transition: BlockNumber,
+	/// Number of first block where EIP-1559 rules begin.
+	pub eip1559_transition: BlockNumber,
 	/// Number of first block where dust cleanup rules (EIP-168 and EIP169) begin.
 	pub dust_protection_transition: BlockNumber,
 	/// Nonce cap increase per block. Nonce cap is only checked if dust protection is enabled.
@@ -211,6 +216,16 @@ impl CommonParams {
 		}
 	}
 
+	/// Whether transactions of the given EIP-2718 type are valid at given block.
+	/// EIP-1559 transactions need the EIP-2718 envelope as well.
+	pub fn is_transaction_type_enabled(&self, tx_type: TypedTxId, block_number: BlockNumber) -> bool {
+		match tx_type {
+			TypedTxId::Legacy => true,
+			TypedTxId::AccessList => block_number >= self.eip2930_transition,
+			TypedTxId::EIP1559 => block_number >= self.eip2930_transition && block_number >= self.eip1559_transition,
+		}
+	}
+
 	/// Return Some if the current parameters contain a bugfix hard fork not on block 0.
 	pub fn nonzero_bugfix_hard_fork(&self) -> Option<&str> {
 		if self.eip155_transition != 0 {
@@ -308,6 +323,14 @@ impl From<ethjson::spec::Params> for CommonParams {
 				BlockNumber::max_value,
 				Into::into,
 			),
+			eip2930_transition: p.eip2930_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
+			eip1559_transition: p.eip1559_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
 			dust_protection_transition: p.dust_protection_transition.map_or_else(
 				BlockNumber::max_value,
 				Into::into,
@@ -753,6 +776,9 @@ impl Spec {
 		header.set_gas_used(self.gas_used.clone());
 		header.set_gas_limit(self.gas_limit.clone());
 		header.set_difficulty(self.difficulty.clone());
+		if self.params().eip1559_transition == 0 {
+			header.set_base_fee_per_gas(Some(EIP1559_INITIAL_BASE_FEE.into()));
+		}
 		header.set_seal({
 			let r = Rlp::new(&self.seal_rlp);
 			r.iter().map(|f| f.as_raw().to_vec()).collect()
@@ -929,6 +955,19 @@ impl Spec {
 		load_bundled!("null")
 	}
 
+	/// Create a new Spec like `new_null` whose headers carry an EIP-1559 base fee
+	/// from block 1 on.
+	#[cfg(any(test, feature = "test-helpers"))]
+	pub fn new_null_eip1559() -> Spec {
+		load_bundled!("null_eip1559")
+	}
+
+	/// Create a new Spec like `new_null_eip1559` whose genesis already carries a base fee.
+	#[cfg(any(test, feature = "test-helpers"))]
+	pub fn new_null_eip1559_genesis() -> Spec {
+		load_bundled!("null_eip1559_genesis")
+	}
+
 	/// Create a new Spec which constructs a contract at address 5 with storage at 0 equal to 1.
 	#[cfg(any(test, feature = "test-helpers"))]
 	pub fn new_test_constructor() -> Spec {
@@ -1038,4 +1077,19 @@ mod tests {
 		assert_eq!(state.storage_at(&address, &H256::zero()).unwrap(), expected);
 		assert_eq!(state.balance(&address).unwrap(), 1.into());
 	}
+
+	#[test]
+	fn genesis_carries_base_fee_from_eip1559_transition_at_zero() {
+		let spec = Spec::new_null_eip1559_genesis();
+		let genesis = spec.genesis_header();
+		assert_eq!(genesis.base_fee_per_gas(), Some(EIP1559_INITIAL_BASE_FEE.into()));
+
+		let encoded = ::rlp::encode(&genesis);
+		let decoded = Header::decode_rlp(&Rlp::new(&encoded), spec.params().eip1559_transition).unwrap();
+		assert_eq!(decoded.hash(), genesis.hash());
+		assert_eq!(decoded.base_fee_per_gas(), genesis.base_fee_per_gas());
+		assert_eq!(decoded.seal(), genesis.seal());
+
+		assert_eq!(Spec::new_null_eip1559().genesis_header().base_fee_per_gas(), None);
+	}
 }
@@ -175,11 +175,12 @@ pub fn generate_dummy_client_with_spec_and_data<F>(test_spec: F, block_number: u
 
 		let b = b.close_and_lock().unwrap().seal(test_engine, vec![]).unwrap();
 
-		if let Err(e) = client.import_block(Unverified::from_rlp(b.rlp_bytes()).unwrap()) {
+		let eip1559_transition = test_engine.params().eip1559_transition;
+		if let Err(e) = client.import_block(Unverified::from_rlp(b.rlp_bytes(), eip1559_transition).unwrap()) {
 			panic!("error importing block which is valid by definition: {:?}", e);
 		}
 
-		last_header = view!(BlockView, &b.rlp_bytes()).header();
+		last_header = view!(BlockView, &b.rlp_bytes()).header(eip1559_transition);
 		db = b.drain().state.drop().1;
 	}
 	client.flush_queue();
@@ -211,7 +212,7 @@ pub fn push_blocks_to_client(client: &Arc<Client>, timestamp_salt: u64, starting
 		rolling_block_number = rolling_block_number + 1;
 		rolling_timestamp = rolling_timestamp + 10;
 
-		if let Err(e) = client.import_block(Unverified::from_rlp(create_test_block(&header)).unwrap()) {
+		if let Err(e) = client.import_block(Unverified::from_rlp(create_test_block(&header), test_spec.params().eip1559_transition).unwrap()) {
 			panic!("error importing block which is valid by definition: {:?}", e);
 		}
 	}
@@ -231,7 +232,7 @@ pub fn push_block_with_transactions(client: &Arc<Client>, transactions: &[Signed
 	}
 	let b = b.close_and_lock().unwrap().seal(test_engine, vec![]).unwrap();
 
-	if let Err(e) = client.import_block(Unverified::from_rlp(b.rlp_bytes()).unwrap()) {
+	if let Err(e) = client.import_block(Unverified::from_rlp(b.rlp_bytes(), test_engine.params().eip1559_transition).unwrap()) {
 		panic!("error importing block which is valid by definition: {:?}", e);
 	}
 
@@ -253,7 +254,7 @@ pub fn get_test_client_with_blocks(blocks: Vec<Bytes>) -> Arc<Client> {
 	).unwrap();
 
 	for block in blocks {
-		if let Err(e) = client.import_block(Unverified::from_rlp(block).unwrap()) {
+		if let Err(e) = client.import_block(Unverified::from_rlp(block, test_spec.params().eip1559_transition).unwrap()) {
 			panic!("error importing block which is well-formed: {:?}", e);
 		}
 	}
@@ -98,7 +98,7 @@ fn imports_good_block() {
 		IoChannel::disconnected(),
 	).unwrap();
 	let good_block = get_good_dummy_block();
-	if client.import_block(Unverified::from_rlp(good_block).unwrap()).is_err() {
+	if client.import_block(Unverified::from_rlp(good_block, spec.params().eip1559_transition).unwrap()).is_err() {
 		panic!("error importing block being good by definition");
 	}
 	client.flush_queue();
@@ -138,7 +138,7 @@ fn returns_chain_info() {
 	let client = get_test_client_with_blocks(vec![dummy_block.clone()]);
 	let block = view!(BlockView, &dummy_block);
 	let info = client.chain_info();
-	assert_eq!(info.best_block_hash, block.header().hash());
+	assert_eq!(info.best_block_hash, block.hash());
 }
 
 #[test]
@@ -174,7 +174,7 @@ fn returns_block_body() {
 	let dummy_block = get_good_dummy_block();
 	let client = get_test_client_with_blocks(vec![dummy_block.clone()]);
 	let block = view!(BlockView, &dummy_block);
-	let body = client.block_body(BlockId::Hash(block.header().hash())).unwrap();
+	let body = client.block_body(BlockId::Hash(block.hash())).unwrap();
 	let body = body.rlp();
 	assert_eq!(body.item_count().unwrap(), 2);
 	assert_eq!(body.at(0).unwrap().as_raw()[..], block.rlp().at(1).as_raw()[..]);
@@ -293,6 +293,50 @@ fn change_history_size() {
 	assert_eq!(client.state().balance(&address).unwrap(), 100.into());
 }
 
+#[test]
+fn imports_blocks_sealed_after_eip1559_transition() {
+	imports_blocks_sealed_with_base_fee(Spec::new_null_eip1559());
+}
+
+#[test]
+fn imports_blocks_sealed_after_eip1559_transition_at_genesis() {
+	imports_blocks_sealed_with_base_fee(Spec::new_null_eip1559_genesis());
+}
+
+fn imports_blocks_sealed_with_base_fee(spec: Spec) {
+	let eip1559_transition = spec.params().eip1559_transition;
+	let new_client = || Client::new(
+		ClientConfig::default(),
+		&spec,
+		test_helpers::new_db(),
+		Arc::new(Miner::new_for_tests(&spec, None)),
+		IoChannel::disconnected(),
+	).unwrap();
+
+	let client = new_client();
+	for _ in 0..3 {
+		let b = client.prepare_open_block(Address::default(), (3141562.into(), 31415620.into()), vec![]).unwrap();
+		let b = b.close_and_lock().unwrap().seal(&*spec.engine, vec![]).unwrap();
+		client.import_sealed_block(b).unwrap();
+	}
+
+	// parents are read back from the database with their base fee.
+	let best = client.block_header_decoded(BlockId::Latest).unwrap();
+	assert_eq!(best.number(), 3);
+	assert!(best.seal().is_empty());
+	assert!(best.base_fee_per_gas().unwrap() < U256::from(1_000_000_000));
+
+	let sync_client = new_client();
+	for n in 1..4 {
+		let block = client.block(BlockId::Number(n)).unwrap();
+		sync_client.import_block(Unverified::from_rlp(block.into_inner(), eip1559_transition).unwrap()).unwrap();
+	}
+	sync_client.flush_queue();
+	sync_client.import_verified_blocks();
+
+	assert_eq!(sync_client.chain_info().best_block_hash, client.chain_info().best_block_hash);
+}
+
 #[test]
 fn does_not_propagate_delayed_transactions() {
 	let key = KeyPair::from_secret(keccak("test").into()).unwrap();
@@ -71,6 +71,7 @@ pub mod blocks {
 	use engines::EthEngine;
 	use error::{Error, ErrorKind, BlockError};
 	use types::header::Header;
+	use types::BlockNumber;
 	use verification::{PreverifiedBlock, verify_block_basic, verify_block_unordered};
 	use types::transaction::UnverifiedTransaction;
 
@@ -126,14 +127,15 @@ pub mod blocks {
 	}
 
 	impl Unverified {
-		/// Create an `Unverified` from raw bytes.
-		pub fn from_rlp(bytes: Bytes) -> Result<Self, ::rlp::DecoderError> {
+		/// Create an `Unverified` from raw bytes, decoding headers of blocks at or after
+		/// `eip1559_transition` with their base fee.
+		pub fn from_rlp(bytes: Bytes, eip1559_transition: BlockNumber) -> Result<Self, ::rlp::DecoderError> {
 			use rlp::Rlp;
 			let (header, transactions, uncles) = {
 				let rlp = Rlp::new(&bytes);
-				let header = rlp.val_at(0)?;
+				let header = Header::decode_rlp(&rlp.at(0)?, eip1559_transition)?;
 				let transactions = rlp.list_at(1)?;
-				let uncles = rlp.list_at(2)?;
+				let uncles = Header::decode_rlp_list(&rlp.at(2)?, eip1559_transition)?;
 				(header, transactions, uncles)
 			};
 
@@ -747,6 +747,7 @@ mod tests {
 	use bytes::Bytes;
 	use types::view;
 	use types::views::BlockView;
+	use types::BlockNumber;
 
 	// create a test block queue.
 	// auto_scaling enables verifier adjustment.
@@ -767,7 +768,7 @@ mod tests {
 	}
 
 	fn new_unverified(bytes: Bytes) -> Unverified {
-		Unverified::from_rlp(bytes).expect("Should be valid rlp")
+		Unverified::from_rlp(bytes, BlockNumber::max_value()).expect("Should be valid rlp")
 	}
 
 	#[test]
@@ -809,7 +810,7 @@ mod tests {
 	fn returns_total_difficulty() {
 		let queue = get_test_queue(false);
 		let block = get_good_dummy_block();
-		let hash = view!(BlockView, &block).header().hash().clone();
+		let hash = view!(BlockView, &block).hash();
 		if let Err(e) = queue.import(new_unverified(block)) {
 			panic!("error importing block that is valid by definition({:?})", e);
 		}
@@ -825,7 +826,7 @@ mod tests {
 	fn returns_ok_for_drained_duplicates() {
 		let queue = get_test_queue(false);
 		let block = get_good_dummy_block();
-		let hash = view!(BlockView, &block).header().hash().clone();
+		let hash = view!(BlockView, &block).hash();
 		if let Err(e) = queue.import(new_unverified(block)) {
 			panic!("error importing block that is valid by definition({:?})", e);
 		}
@@ -239,7 +239,7 @@ fn verify_uncles(block: &PreverifiedBlock, bc: &BlockProvider, engine: &EthEngin
 				return Err(From::from(BlockError::UncleParentNotInChain(uncle_parent.hash())));
 			}
 
-			let uncle_parent = uncle_parent.decode()?;
+			let uncle_parent = uncle_parent.decode(engine.params().eip1559_transition)?;
 			verify_parent(&uncle, &uncle_parent, engine)?;
 			engine.verify_block_family(&uncle, &uncle_parent)?;
 			verified.insert(uncle.hash());
@@ -283,6 +283,12 @@ pub fn verify_header_params(header: &Header, engine: &EthEngine, is_full: bool,
 	if header.gas_used() > header.gas_limit() {
 		return Err(From::from(BlockError::TooMuchGasUsed(OutOfBounds { max: Some(*header.gas_limit()), min: None, found: *header.gas_used() })));
 	}
+	// Only guards headers built locally: `Header::decode_rlp` never reads a base fee
+	// before the transition, so a decoded header carrying one instead has an extra
+	// seal field and is caught by the seal arity check above.
+	if header.number() < engine.params().eip1559_transition && header.base_fee_per_gas().is_some() {
+		return Err(From::from(BlockError::InvalidBaseFee(Mismatch { expected: None, found: header.base_fee_per_gas() })));
+	}
 	let min_gas_limit = engine.params().min_gas_limit;
 	if header.gas_limit() < &min_gas_limit {
 		return Err(From::from(BlockError::InvalidGasLimit(OutOfBounds { min: Some(min_gas_limit), max: None, found: *header.gas_limit() })));
@@ -355,6 +361,8 @@ fn verify_parent(header: &Header, parent: &Header, engine: &EthEngine) -> Result
 		return Err(From::from(BlockError::InvalidGasLimit(OutOfBounds { min: Some(min_gas), max: Some(max_gas), found: *header.gas_limit() })));
 	}
 
+	engine.verify_base_fee(header, parent)?;
+
 	Ok(())
 }
 
@@ -425,6 +433,7 @@ mod tests {
 	struct TestBlockChain {
 		blocks: HashMap<H256, Bytes>,
 		numbers: HashMap<BlockNumber, H256>,
+		eip1559_transition: BlockNumber,
 	}
 
 	impl Default for TestBlockChain {
@@ -438,11 +447,12 @@ mod tests {
 			TestBlockChain {
 				blocks: HashMap::new(),
 				numbers: HashMap::new(),
+				eip1559_transition: BlockNumber::max_value(),
 			}
 		}
 
 		pub fn insert(&mut self, bytes: Bytes) {
-			let header = Unverified::from_rlp(bytes.clone()).unwrap().header;
+			let header = Unverified::from_rlp(bytes.clone(), self.eip1559_transition).unwrap().header;
 			let hash = header.hash();
 			self.blocks.insert(hash, bytes);
 			self.numbers.insert(header.number(), hash);
@@ -458,6 +468,10 @@ mod tests {
 			unimplemented!()
 		}
 
+		fn eip1559_transition(&self) -> BlockNumber {
+			self.eip1559_transition
+		}
+
 		/// Get raw block data
 		fn block(&self, hash: &H256) -> Option<encoded::Block> {
 			self.blocks.get(hash).cloned().map(encoded::Block::new)
@@ -482,7 +496,7 @@ mod tests {
 		/// Get the familial details concerning a block.
 		fn block_details(&self, hash: &H256) -> Option<BlockDetails> {
 			self.blocks.get(hash).map(|bytes| {
-				let header = Unverified::from_rlp(bytes.to_vec()).unwrap().header;
+				let header = Unverified::from_rlp(bytes.to_vec(), self.eip1559_transition).unwrap().header;
 				BlockDetails {
 					number: header.number(),
 					total_difficulty: *header.difficulty(),
@@ -518,12 +532,12 @@ mod tests {
 	}
 
 	fn basic_test(bytes: &[u8], engine: &EthEngine) -> Result<(), Error> {
-		let unverified = Unverified::from_rlp(bytes.to_vec())?;
+		let unverified = Unverified::from_rlp(bytes.to_vec(), engine.params().eip1559_transition)?;
 		verify_block_basic(&unverified, engine, true)
 	}
 
 	fn family_test<BC>(bytes: &[u8], engine: &EthEngine, bc: &BC) -> Result<(), Error> where BC: BlockProvider {
-		let block = Unverified::from_rlp(bytes.to_vec()).unwrap();
+		let block = Unverified::from_rlp(bytes.to_vec(), engine.params().eip1559_transition).unwrap();
 		let header = block.header;
 		let transactions: Vec<_> = block.transactions
 			.into_iter()
@@ -537,7 +551,7 @@ mod tests {
 		let client = ::client::TestBlockChainClient::default();
 		let parent = bc.block_header_data(header.parent_hash())
 			.ok_or(BlockError::UnknownParent(*header.parent_hash()))?
-			.decode()?;
+			.decode(engine.params().eip1559_transition)?;
 
 		let block = PreverifiedBlock {
 			header,
@@ -555,7 +569,7 @@ mod tests {
 	}
 
 	fn unordered_test(bytes: &[u8], engine: &EthEngine) -> Result<(), Error> {
-		let un = Unverified::from_rlp(bytes.to_vec())?;
+		let un = Unverified::from_rlp(bytes.to_vec(), engine.params().eip1559_transition)?;
 		verify_block_unordered(un, engine, false)?;
 		Ok(())
 	}
@@ -824,4 +838,60 @@ mod tests {
 		check_fail(unordered_test(&create_test_block_with_data(&header, &bad_transactions, &[]), &engine), TooManyTransactions(keypair.address()));
 		unordered_test(&create_test_block_with_data(&header, &good_transactions, &[]), &engine).unwrap();
 	}
+
+	#[test]
+	fn rejects_base_fee_before_eip1559_transition() {
+		let spec = Spec::new_null_eip1559();
+		let engine = &*spec.engine;
+
+		let mut header = Header::new();
+		header.set_gas_limit(engine.params().min_gas_limit);
+		check_ok(verify_header_params(&header, engine, false, false));
+
+		header.set_base_fee_per_gas(Some(1.into()));
+		check_fail(verify_header_params(&header, engine, false, false),
+			InvalidBaseFee(Mismatch { expected: None, found: Some(1.into()) }));
+
+		header.set_number(1);
+		check_ok(verify_header_params(&header, engine, false, false));
+	}
+
+	#[test]
+	fn checks_base_fee_against_parent() {
+		use machine::EIP1559_INITIAL_BASE_FEE;
+
+		let spec = Spec::new_null_eip1559();
+		let engine = &*spec.engine;
+		let initial_base_fee = U256::from(EIP1559_INITIAL_BASE_FEE);
+
+		let mut parent = Header::new();
+		parent.set_gas_limit(10_000_000.into());
+		parent.set_gas_used(10_000_000.into());
+		parent.set_timestamp(40);
+
+		let mut header = Header::new();
+		header.set_number(1);
+		header.set_parent_hash(parent.hash());
+		header.set_gas_limit(10_000_000.into());
+		header.set_timestamp(50);
+
+		// the transition block starts from the initial base fee.
+		header.set_base_fee_per_gas(Some(initial_base_fee));
+		check_ok(verify_parent(&header, &parent, engine));
+		header.set_base_fee_per_gas(None);
+		check_fail(verify_parent(&header, &parent, engine),
+			InvalidBaseFee(Mismatch { expected: Some(initial_base_fee), found: None }));
+
+		// a full parent raises the base fee by 12.5%.
+		parent.set_number(1);
+		parent.set_base_fee_per_gas(Some(initial_base_fee));
+		header.set_number(2);
+		header.set_parent_hash(parent.hash());
+		header.set_base_fee_per_gas(Some(1_125_000_000.into()));
+		check_ok(verify_parent(&header, &parent, engine));
+
+		header.set_base_fee_per_gas(Some(initial_base_fee));
+		check_fail(verify_parent(&header, &parent, engine),
+			InvalidBaseFee(Mismatch { expected: Some(1_125_000_000.into()), found: Some(initial_base_fee) }));
+	}
 }
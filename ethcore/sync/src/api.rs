@@ -118,6 +118,8 @@ pub struct SyncConfig {
 	pub warp_sync: WarpSync,
 	/// Enable light client server.
 	pub serve_light: bool,
+	/// Number of first block whose header carries a base fee (EIP-1559).
+	pub eip1559_transition: BlockNumber,
 }
 
 impl Default for SyncConfig {
@@ -131,6 +133,7 @@ impl Default for SyncConfig {
 			fork_block: None,
 			warp_sync: WarpSync::Disabled,
 			serve_light: false,
+			eip1559_transition: BlockNumber::max_value(),
 		}
 	}
 }
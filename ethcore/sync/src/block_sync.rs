@@ -141,12 +141,14 @@ pub struct BlockDownloader {
 	retract_step: u64,
 	/// consecutive useless headers this round
 	useless_headers_count: usize,
+	/// Number of first block whose header carries a base fee (EIP-1559).
+	eip1559_transition: BlockNumber,
 }
 
 impl BlockDownloader {
 	/// Create a new instance of syncing strategy.
 	/// For BlockSet::NewBlocks this won't reorganize to before the last kept state.
-	pub fn new(block_set: BlockSet, start_hash: &H256, start_number: BlockNumber) -> Self {
+	pub fn new(block_set: BlockSet, start_hash: &H256, start_number: BlockNumber, eip1559_transition: BlockNumber) -> Self {
 		let sync_receipts = match block_set {
 			BlockSet::NewBlocks => false,
 			BlockSet::OldBlocks => true
@@ -166,6 +168,7 @@ impl BlockDownloader {
 			target_hash: None,
 			retract_step: 1,
 			useless_headers_count: 0,
+			eip1559_transition,
 		}
 	}
 
@@ -260,7 +263,7 @@ impl BlockDownloader {
 		let mut hashes = Vec::new();
 		let mut last_header = None;
 		for i in 0..item_count {
-			let info = SyncHeader::from_rlp(r.at(i)?.as_raw().to_vec())?;
+			let info = SyncHeader::from_rlp(r.at(i)?.as_raw().to_vec(), self.eip1559_transition)?;
 			let number = BlockNumber::from(info.header.number());
 			let hash = info.header.hash();
 
@@ -371,7 +374,7 @@ impl BlockDownloader {
 		} else {
 			let mut bodies = Vec::with_capacity(item_count);
 			for i in 0..item_count {
-				let body = SyncBody::from_rlp(r.at(i)?.as_raw())?;
+				let body = SyncBody::from_rlp(r.at(i)?.as_raw(), self.eip1559_transition)?;
 				bodies.push(body);
 			}
 
@@ -680,7 +683,7 @@ mod tests {
 		let spec = Spec::new_test();
 		let genesis_hash = spec.genesis_header().hash();
 
-		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &genesis_hash, 0);
+		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &genesis_hash, 0, BlockNumber::max_value());
 		downloader.state = State::ChainHead;
 
 		let mut chain = TestBlockChainClient::new();
@@ -762,7 +765,7 @@ mod tests {
 		let parent_hash = headers[1].hash();
 		headers.push(dummy_header(129, parent_hash));
 
-		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &H256::random(), 0);
+		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &H256::random(), 0, BlockNumber::max_value());
 		downloader.state = State::Blocks;
 		downloader.blocks.reset_to(vec![headers[0].hash()]);
 
@@ -832,7 +835,7 @@ mod tests {
 			headers.push(header);
 		}
 
-		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &headers[0].hash(), 0);
+		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &headers[0].hash(), 0, BlockNumber::max_value());
 		downloader.state = State::Blocks;
 		downloader.blocks.reset_to(vec![headers[0].hash()]);
 
@@ -896,7 +899,7 @@ mod tests {
 			headers.push(header);
 		}
 
-		let mut downloader = BlockDownloader::new(BlockSet::OldBlocks, &headers[0].hash(), 0);
+		let mut downloader = BlockDownloader::new(BlockSet::OldBlocks, &headers[0].hash(), 0, BlockNumber::max_value());
 		downloader.state = State::Blocks;
 		downloader.blocks.reset_to(vec![headers[0].hash()]);
 
@@ -929,7 +932,7 @@ mod tests {
 		let spec = Spec::new_test();
 		let genesis_hash = spec.genesis_header().hash();
 
-		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &genesis_hash, 0);
+		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &genesis_hash, 0, BlockNumber::max_value());
 		downloader.state = State::ChainHead;
 
 		let mut chain = TestBlockChainClient::new();
@@ -969,7 +972,7 @@ mod tests {
 		let spec = Spec::new_test();
 		let genesis_hash = spec.genesis_header().hash();
 
-		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &genesis_hash, 0);
+		let mut downloader = BlockDownloader::new(BlockSet::NewBlocks, &genesis_hash, 0, BlockNumber::max_value());
 		downloader.state = State::ChainHead;
 
 		let mut chain = TestBlockChainClient::new();
@@ -17,6 +17,7 @@
 use tests::helpers::TestNet;
 
 use ethcore::client::{BlockInfo, BlockId, EachBlockWith};
+use types::BlockNumber;
 
 mod test_net;
 
@@ -45,7 +46,7 @@ fn fork_post_cht() {
 	for id in (0..CHAIN_LENGTH).map(|x| x + 1).map(BlockId::Number) {
 		let (light_peer, full_peer) = (net.peer(0), net.peer(1));
 		let light_chain = light_peer.light_chain();
-		let header = full_peer.chain().block_header(id).unwrap().decode().expect("decoding failure");
+		let header = full_peer.chain().block_header(id).unwrap().decode(BlockNumber::max_value()).expect("decoding failure");
 		let _  = light_chain.import_header(header);
 		light_chain.flush_queue();
 		light_chain.import_verified();
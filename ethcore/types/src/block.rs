@@ -34,8 +34,9 @@
 use bytes::Bytes;
 
 use header::Header;
-use rlp::{Rlp, RlpStream, Decodable, DecoderError};
+use rlp::{Rlp, RlpStream, DecoderError};
 use transaction::UnverifiedTransaction;
+use BlockNumber;
 
 /// A block, encoded as it is on the block chain.
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -57,10 +58,10 @@ impl Block {
 		block_rlp.append_list(&self.uncles);
 		block_rlp.out()
 	}
-}
 
-impl Decodable for Block {
-	fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+	/// Decode a block, knowing that headers of blocks at or after `eip1559_transition`
+	/// carry the base fee. See `Header::decode_rlp`.
+	pub fn decode_rlp(rlp: &Rlp, eip1559_transition: BlockNumber) -> Result<Self, DecoderError> {
 		if rlp.as_raw().len() != rlp.payload_info()?.total() {
 			return Err(DecoderError::RlpIsTooBig);
 		}
@@ -68,9 +69,9 @@ impl Decodable for Block {
 			return Err(DecoderError::RlpIncorrectListLen);
 		}
 		Ok(Block {
-			header: rlp.val_at(0)?,
+			header: Header::decode_rlp(&rlp.at(0)?, eip1559_transition)?,
 			transactions: rlp.list_at(1)?,
-			uncles: rlp.list_at(2)?,
+			uncles: Header::decode_rlp_list(&rlp.at(2)?, eip1559_transition)?,
 		})
 	}
 }
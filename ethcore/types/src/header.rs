@@ -20,7 +20,7 @@ use hash::{KECCAK_NULL_RLP, KECCAK_EMPTY_LIST_RLP, keccak};
 use heapsize::HeapSizeOf;
 use ethereum_types::{H256, U256, Address, Bloom};
 use bytes::Bytes;
-use rlp::{Rlp, RlpStream, Encodable, DecoderError, Decodable};
+use rlp::{Rlp, RlpStream, Encodable, DecoderError};
 use BlockNumber;
 
 /// Semantic boolean for when a seal/signature is included.
@@ -77,6 +77,8 @@ pub struct Header {
 	gas_used: U256,
 	/// Block gas limit.
 	gas_limit: U256,
+	/// Base fee per gas (EIP-1559), `None` before the transition.
+	base_fee_per_gas: Option<U256>,
 
 	/// Block difficulty.
 	difficulty: U256,
@@ -107,6 +109,7 @@ impl PartialEq for Header {
 		self.log_bloom == c.log_bloom &&
 		self.gas_used == c.gas_used &&
 		self.gas_limit == c.gas_limit &&
+		self.base_fee_per_gas == c.base_fee_per_gas &&
 		self.difficulty == c.difficulty &&
 		self.seal == c.seal
 	}
@@ -129,6 +132,7 @@ impl Default for Header {
 			log_bloom: Bloom::default(),
 			gas_used: U256::default(),
 			gas_limit: U256::default(),
+			base_fee_per_gas: None,
 
 			difficulty: U256::default(),
 			seal: vec![],
@@ -177,6 +181,9 @@ impl Header {
 	/// Get the gas limit field of the header.
 	pub fn gas_limit(&self) -> &U256 { &self.gas_limit }
 
+	/// Get the base fee field of the header, if it has one.
+	pub fn base_fee_per_gas(&self) -> Option<U256> { self.base_fee_per_gas }
+
 	/// Get the difficulty field of the header.
 	pub fn difficulty(&self) -> &U256 { &self.difficulty }
 
@@ -250,6 +257,11 @@ impl Header {
 		change_field(&mut self.hash, &mut self.gas_limit, a);
 	}
 
+	/// Set the base fee field of the header.
+	pub fn set_base_fee_per_gas(&mut self, a: Option<U256>) {
+		change_field(&mut self.hash, &mut self.base_fee_per_gas, a);
+	}
+
 	/// Set the difficulty field of the header.
 	pub fn set_difficulty(&mut self, a: U256) {
 		change_field(&mut self.hash, &mut self.difficulty, a);
@@ -290,11 +302,14 @@ impl Header {
 	}
 
 	/// Place this header into an RLP stream `s`, optionally `with_seal`.
+	///
+	/// The base fee, if any, always goes last, after the seal fields.
 	fn stream_rlp(&self, s: &mut RlpStream, with_seal: Seal) {
+		let base_fee_len = if self.base_fee_per_gas.is_some() { 1 } else { 0 };
 		if let Seal::With = with_seal {
-			s.begin_list(13 + self.seal.len());
+			s.begin_list(13 + self.seal.len() + base_fee_len);
 		} else {
-			s.begin_list(13);
+			s.begin_list(13 + base_fee_len);
 		}
 
 		s.append(&self.parent_hash);
@@ -316,19 +331,15 @@ impl Header {
 				s.append_raw(b, 1);
 			}
 		}
-	}
-}
 
-/// Alter value of given field, reset memoised hash if changed.
-fn change_field<T>(hash: &mut Option<H256>, field: &mut T, value: T) where T: PartialEq<T> {
-	if field != &value {
-		*field = value;
-		*hash = None;
+		if let Some(ref base_fee) = self.base_fee_per_gas {
+			s.append(base_fee);
+		}
 	}
-}
 
-impl Decodable for Header {
-	fn decode(r: &Rlp) -> Result<Self, DecoderError> {
+	/// Decode a header, knowing that headers of blocks at or after `eip1559_transition`
+	/// carry the base fee as their last item, after the seal fields.
+	pub fn decode_rlp(r: &Rlp, eip1559_transition: BlockNumber) -> Result<Self, DecoderError> {
 		let mut blockheader = Header {
 			parent_hash: r.val_at(0)?,
 			uncles_hash: r.val_at(1)?,
@@ -343,16 +354,39 @@ impl Decodable for Header {
 			gas_used: r.val_at(10)?,
 			timestamp: r.val_at(11)?,
 			extra_data: r.val_at(12)?,
+			base_fee_per_gas: None,
 			seal: vec![],
 			hash: keccak(r.as_raw()).into(),
 		};
 
-		for i in 13..r.item_count()? {
+		let mut seal_end = r.item_count()?;
+		if blockheader.number >= eip1559_transition {
+			if seal_end <= 13 {
+				return Err(DecoderError::RlpIncorrectListLen);
+			}
+			seal_end -= 1;
+			blockheader.base_fee_per_gas = Some(r.val_at(seal_end)?);
+		}
+
+		for i in 13..seal_end {
 			blockheader.seal.push(r.at(i)?.as_raw().to_vec())
 		}
 
 		Ok(blockheader)
 	}
+
+	/// Decode a list of headers, e.g. the uncles of a block, with `decode_rlp`.
+	pub fn decode_rlp_list(r: &Rlp, eip1559_transition: BlockNumber) -> Result<Vec<Self>, DecoderError> {
+		r.iter().map(|h| Header::decode_rlp(&h, eip1559_transition)).collect()
+	}
+}
+
+/// Alter value of given field, reset memoised hash if changed.
+fn change_field<T>(hash: &mut Option<H256>, field: &mut T, value: T) where T: PartialEq<T> {
+	if field != &value {
+		*field = value;
+		*hash = None;
+	}
 }
 
 impl Encodable for Header {
@@ -377,7 +411,9 @@ impl ExtendedHeader {
 #[cfg(test)]
 mod tests {
 	use rustc_hex::FromHex;
-	use rlp;
+	use rlp::{self, Rlp};
+	use ethereum_types::U256;
+	use BlockNumber;
 	use super::Header;
 
 	#[test]
@@ -389,7 +425,7 @@ mod tests {
 		let nonce = "88ab4e252a7e8c2a23".from_hex().unwrap();
 		let nonce_decoded = "ab4e252a7e8c2a23".from_hex().unwrap();
 
-		let header: Header = rlp::decode(&header_rlp).expect("error decoding header");
+		let header = Header::decode_rlp(&Rlp::new(&header_rlp), BlockNumber::max_value()).expect("error decoding header");
 		let seal_fields = header.seal.clone();
 		assert_eq!(seal_fields.len(), 2);
 		assert_eq!(seal_fields[0], mix_hash);
@@ -406,7 +442,7 @@ mod tests {
 		// that's rlp of block header created with ethash engine.
 		let header_rlp = "f901f9a0d405da4e66f1445d455195229624e133f5baafe72b5cf7b3c36c12c8146e98b7a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347948888f1f195afa192cfee860698584c030f4c9db1a05fb2b4bfdef7b314451cb138a534d225c922fc0e5fbe25e451142732c3e25c25a088d2ec6b9860aae1a2c3b299f72b6a5d70d7f7ba4722c78f2c49ba96273c2158a007c6fdfa8eea7e86b81f5b0fc0f78f90cc19f4aa60d323151e0cac660199e9a1b90100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008302008003832fefba82524d84568e932a80a0a0349d8c3df71f1a48a9df7d03fd5f14aeee7d91332c009ecaff0a71ead405bd88ab4e252a7e8c2a23".from_hex().unwrap();
 
-		let header: Header = rlp::decode(&header_rlp).expect("error decoding header");
+		let header = Header::decode_rlp(&Rlp::new(&header_rlp), BlockNumber::max_value()).expect("error decoding header");
 		let encoded_header = rlp::encode(&header);
 
 		assert_eq!(header_rlp, encoded_header);
@@ -419,7 +455,37 @@ mod tests {
 		let header_rlp = "f901f9a0d405da4e66f1445d455195229624e133f5baafe72b5cf7b3c36c12c8146e98b7a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347948888f1f195afa192cfee860698584c030f4c9db1a05fb2b4bfdef7b314451cb138a534d225c922fc0e5fbe25e451142732c3e25c25a088d2ec6b9860aae1a2c3b299f72b6a5d70d7f7ba4722c78f2c49ba96273c2158a007c6fdfa8eea7e86b81f5b0fc0f78f90cc19f4aa60d323151e0cac660199e9a1b90100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008302008003832fefba82524d891000000000000000000080a0a0349d8c3df71f1a48a9df7d03fd5f14aeee7d91332c009ecaff0a71ead405bd88ab4e252a7e8c2a23".from_hex().unwrap();
 
 		// This should fail decoding timestamp
-		let header: Result<Header, _> = rlp::decode(&header_rlp);
+		let header = Header::decode_rlp(&Rlp::new(&header_rlp), BlockNumber::max_value());
 		assert_eq!(header.unwrap_err(), rlp::DecoderError::RlpIsTooBig);
 	}
+
+	#[test]
+	fn decode_and_encode_header_with_base_fee() {
+		let mut header = Header::default();
+		header.set_number(10);
+		header.set_seal(vec![rlp::encode(&1u8), rlp::encode(&2u8)]);
+		header.set_base_fee_per_gas(Some(U256::from(1_000_000_000)));
+
+		let encoded = rlp::encode(&header);
+		let decoded = Header::decode_rlp(&Rlp::new(&encoded), 10).expect("error decoding header");
+		assert_eq!(decoded.base_fee_per_gas(), Some(U256::from(1_000_000_000)));
+		assert_eq!(decoded.seal().len(), 2);
+		assert_eq!(decoded.hash(), header.hash());
+		assert_eq!(decoded, header);
+
+		// before the transition the trailing item is indistinguishable from a seal field
+		let decoded = Header::decode_rlp(&Rlp::new(&encoded), 11).expect("error decoding header");
+		assert_eq!(decoded.base_fee_per_gas(), None);
+		assert_eq!(decoded.seal().len(), 3);
+	}
+
+	#[test]
+	fn reject_header_without_base_fee_after_transition() {
+		let mut header = Header::default();
+		header.set_number(10);
+
+		let encoded = rlp::encode(&header);
+		let decoded = Header::decode_rlp(&Rlp::new(&encoded), 10);
+		assert_eq!(decoded.unwrap_err(), rlp::DecoderError::RlpIncorrectListLen);
+	}
 }
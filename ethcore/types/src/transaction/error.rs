@@ -21,6 +21,8 @@ use ethkey;
 use rlp;
 use unexpected::OutOfBounds;
 
+use transaction::TypedTxId;
+
 #[derive(Debug, PartialEq, Clone)]
 /// Errors concerning transaction processing.
 pub enum Error {
@@ -40,6 +42,20 @@ pub enum Error {
 		/// Transaction gas price
 		got: U256,
 	},
+	/// Transaction's gas price is below the block's base fee (EIP-1559).
+	GasPriceLowerThanBaseFee {
+		/// Base fee of the block
+		base_fee: U256,
+		/// Transaction gas price
+		got: U256,
+	},
+	/// Transaction's max priority fee per gas is above its max fee per gas (EIP-1559).
+	PriorityFeeAboveMaxFee {
+		/// Declared max fee per gas
+		max_fee: U256,
+		/// Declared max priority fee per gas
+		got: U256,
+	},
 	/// Transaction's gas is below currently set minimal gas requirement.
 	InsufficientGas {
 		/// Minimal expected gas
@@ -79,6 +95,8 @@ pub enum Error {
 	TooBig,
 	/// Invalid RLP encoding
 	InvalidRlp(String),
+	/// Transaction type is not enabled yet (EIP-2718).
+	TransactionTypeNotEnabled(TypedTxId),
 }
 
 impl From<ethkey::Error> for Error {
@@ -103,6 +121,10 @@ impl fmt::Display for Error {
 			LimitReached => "Transaction limit reached".into(),
 			InsufficientGasPrice { minimal, got } =>
 				format!("Insufficient gas price. Min={}, Given={}", minimal, got),
+			GasPriceLowerThanBaseFee { base_fee, got } =>
+				format!("Gas price lower than base fee. BaseFee={}, Given={}", base_fee, got),
+			PriorityFeeAboveMaxFee { max_fee, got } =>
+				format!("Priority fee above max fee. Max={}, Given={}", max_fee, got),
 			InsufficientGas { minimal, got } =>
 				format!("Insufficient gas. Min={}, Given={}", minimal, got),
 			InsufficientBalance { balance, cost } =>
@@ -119,6 +141,7 @@ impl fmt::Display for Error {
 			NotAllowed => "Sender does not have permissions to execute this type of transction".into(),
 			TooBig => "Transaction too big".into(),
 			InvalidRlp(ref err) => format!("Transaction has invalid RLP structure: {}.", err),
+			TransactionTypeNotEnabled(ref tx_type) => format!("Transaction type {:?} is not enabled.", tx_type),
 		};
 
 		f.write_fmt(format_args!("Transaction error ({})", msg))
@@ -18,6 +18,8 @@
 
 mod error;
 mod transaction;
+mod typed;
 
 pub use self::error::Error;
 pub use self::transaction::*;
+pub use self::typed::*;
@@ -16,6 +16,7 @@
 
 //! Transaction data structure.
 
+use std::cmp;
 use std::ops::Deref;
 
 use ethereum_types::{H256, H160, Address, U256};
@@ -25,7 +26,7 @@ use hash::keccak;
 use heapsize::HeapSizeOf;
 use rlp::{self, RlpStream, Rlp, DecoderError, Encodable};
 
-use transaction::error;
+use transaction::{error, TypedTxId, TypedFields};
 
 type Bytes = Vec<u8>;
 type BlockNumber = u64;
@@ -176,6 +177,7 @@ impl From<ethjson::transaction::Transaction> for UnverifiedTransaction {
 				value: t.value.into(),
 				data: t.data.into(),
 			},
+			typed: None,
 			r: t.r.into(),
 			s: t.s.into(),
 			v: t.v.into(),
@@ -204,6 +206,7 @@ impl Transaction {
 	pub fn with_signature(self, sig: Signature, chain_id: Option<u64>) -> UnverifiedTransaction {
 		UnverifiedTransaction {
 			unsigned: self,
+			typed: None,
 			r: sig.r().into(),
 			s: sig.s().into(),
 			v: signature::add_chain_replay_protection(sig.v() as u64, chain_id),
@@ -216,6 +219,7 @@ impl Transaction {
 	pub fn invalid_sign(self) -> UnverifiedTransaction {
 		UnverifiedTransaction {
 			unsigned: self,
+			typed: None,
 			r: U256::one(),
 			s: U256::one(),
 			v: 0,
@@ -228,6 +232,7 @@ impl Transaction {
 		SignedTransaction {
 			transaction: UnverifiedTransaction {
 				unsigned: self,
+				typed: None,
 				r: U256::one(),
 				s: U256::one(),
 				v: 0,
@@ -243,6 +248,7 @@ impl Transaction {
 		SignedTransaction {
 			transaction: UnverifiedTransaction {
 				unsigned: self,
+				typed: None,
 				r: U256::zero(),
 				s: U256::zero(),
 				v: chain_id,
@@ -259,8 +265,11 @@ impl Transaction {
 pub struct UnverifiedTransaction {
 	/// Plain Transaction.
 	unsigned: Transaction,
+	/// Fields of an EIP-2718 typed transaction, `None` for legacy transactions.
+	typed: Option<TypedFields>,
 	/// The V field of the signature; the LS bit described which half of the curve our point falls
 	/// in. The MS bits describe which chain this transaction is for. If 27/28, its for all chains.
+	/// Typed transactions only store the parity of the `y` coordinate here.
 	v: u64,
 	/// The R field of the signature; helps describe the point on the curve.
 	r: U256,
@@ -272,7 +281,7 @@ pub struct UnverifiedTransaction {
 
 impl HeapSizeOf for UnverifiedTransaction {
 	fn heap_size_of_children(&self) -> usize {
-		self.unsigned.heap_size_of_children()
+		self.unsigned.heap_size_of_children() + self.typed.as_ref().map_or(0, |t| t.heap_size_of_children())
 	}
 }
 
@@ -286,6 +295,9 @@ impl Deref for UnverifiedTransaction {
 
 impl rlp::Decodable for UnverifiedTransaction {
 	fn decode(d: &Rlp) -> Result<Self, DecoderError> {
+		if !d.is_list() {
+			return UnverifiedTransaction::decode_envelope(d.data()?);
+		}
 		if d.item_count()? != 9 {
 			return Err(DecoderError::RlpIncorrectListLen);
 		}
@@ -299,6 +311,7 @@ impl rlp::Decodable for UnverifiedTransaction {
 				value: d.val_at(4)?,
 				data: d.val_at(5)?,
 			},
+			typed: None,
 			v: d.val_at(6)?,
 			r: d.val_at(7)?,
 			s: d.val_at(8)?,
@@ -312,13 +325,75 @@ impl rlp::Encodable for UnverifiedTransaction {
 }
 
 impl UnverifiedTransaction {
+	/// Attaches a signature to the unsigned parts of an EIP-2718 typed transaction.
+	pub(crate) fn typed_with_signature(unsigned: Transaction, typed: TypedFields, sig: Signature) -> Self {
+		UnverifiedTransaction {
+			unsigned: unsigned,
+			typed: Some(typed),
+			r: sig.r().into(),
+			s: sig.s().into(),
+			v: sig.v() as u64,
+			hash: 0.into(),
+		}.compute_hash()
+	}
+
 	/// Used to compute hash of created transactions
 	fn compute_hash(mut self) -> UnverifiedTransaction {
-		let hash = keccak(&*self.rlp_bytes());
+		let hash = keccak(&self.encode_raw());
 		self.hash = hash;
 		self
 	}
 
+	/// Decode raw transaction bytes as produced by `encode_raw`: either a legacy RLP list
+	/// or an EIP-2718 envelope.
+	pub fn decode_raw(bytes: &[u8]) -> Result<Self, DecoderError> {
+		match bytes.first() {
+			Some(&first) if first >= 0xc0 => rlp::decode(bytes),
+			_ => UnverifiedTransaction::decode_envelope(bytes),
+		}
+	}
+
+	/// Decode an EIP-2718 envelope, type byte included.
+	fn decode_envelope(bytes: &[u8]) -> Result<Self, DecoderError> {
+		let first = *bytes.first().ok_or(DecoderError::RlpIsTooShort)?;
+		let tx_type = TypedTxId::from_byte(first).ok_or(DecoderError::Custom("Unknown transaction type"))?;
+		let payload = &bytes[1..];
+		let d = Rlp::new(payload);
+		if d.payload_info()?.total() != payload.len() {
+			return Err(DecoderError::RlpInconsistentLengthAndData);
+		}
+		let field_count = TypedFields::field_count(tx_type);
+		if d.item_count()? != field_count + 3 {
+			return Err(DecoderError::RlpIncorrectListLen);
+		}
+		let (unsigned, typed) = TypedFields::decode_fields(tx_type, &d)?;
+		Ok(UnverifiedTransaction {
+			unsigned: unsigned,
+			typed: Some(typed),
+			v: d.val_at(field_count)?,
+			r: d.val_at(field_count + 1)?,
+			s: d.val_at(field_count + 2)?,
+			hash: keccak(bytes),
+		})
+	}
+
+	/// Hash of a transaction embedded in a list, such as a block body, without decoding it.
+	pub fn hash_rlp(rlp: &Rlp) -> Result<H256, DecoderError> {
+		if rlp.is_list() {
+			Ok(keccak(rlp.as_raw()))
+		} else {
+			rlp.data().map(keccak)
+		}
+	}
+
+	/// Raw transaction bytes: the RLP list of a legacy transaction or the envelope of a typed one.
+	pub fn encode_raw(&self) -> Bytes {
+		match self.typed {
+			Some(ref typed) => typed.encode(&self.unsigned, self.v, &self.r, &self.s),
+			None => self.rlp_bytes(),
+		}
+	}
+
 	/// Checks is signature is empty.
 	pub fn is_unsigned(&self) -> bool {
 		self.r.is_zero() && self.s.is_zero()
@@ -326,6 +401,10 @@ impl UnverifiedTransaction {
 
 	/// Append object with a signature into RLP stream
 	fn rlp_append_sealed_transaction(&self, s: &mut RlpStream) {
+		if let Some(ref typed) = self.typed {
+			s.append(&typed.encode(&self.unsigned, self.v, &self.r, &self.s));
+			return;
+		}
 		s.begin_list(9);
 		s.append(&self.nonce);
 		s.append(&self.gas_price);
@@ -344,13 +423,22 @@ impl UnverifiedTransaction {
 	}
 
 	/// Returns standardized `v` value (0, 1 or 4 (invalid))
-	pub fn standard_v(&self) -> u8 { signature::check_replay_protection(self.v) }
+	pub fn standard_v(&self) -> u8 {
+		match self.typed {
+			Some(_) if self.v <= 1 => self.v as u8,
+			Some(_) => 4,
+			None => signature::check_replay_protection(self.v),
+		}
+	}
 
 	/// The `v` value that appears in the RLP.
 	pub fn original_v(&self) -> u64 { self.v }
 
 	/// The chain ID, or `None` if this is a global transaction.
 	pub fn chain_id(&self) -> Option<u64> {
+		if let Some(ref typed) = self.typed {
+			return Some(typed.chain_id);
+		}
 		match self.v {
 			v if self.is_unsigned() => Some(v),
 			v if v >= 35 => Some((v - 35) / 2),
@@ -372,18 +460,52 @@ impl UnverifiedTransaction {
 		}
 	}
 
-	/// Get the hash of this transaction (keccak of the RLP).
+	/// Get the hash of this transaction (keccak of the RLP, or of the envelope for typed transactions).
 	pub fn hash(&self) -> H256 {
 		self.hash
 	}
 
+	/// EIP-2718 type of this transaction.
+	pub fn tx_type(&self) -> TypedTxId {
+		self.typed.as_ref().map_or(TypedTxId::Legacy, |t| t.tx_type)
+	}
+
+	/// Maximum fee per gas paid to the block author on top of the base fee.
+	/// This is the gas price for all but EIP-1559 transactions.
+	pub fn max_priority_fee_per_gas(&self) -> U256 {
+		match self.typed {
+			Some(ref typed) if typed.tx_type == TypedTxId::EIP1559 => typed.max_priority_fee_per_gas,
+			_ => self.gas_price,
+		}
+	}
+
+	/// Fee per gas actually received by the block author given the block's base fee.
+	///
+	/// Legacy and access list transactions pay their whole gas price above the base fee,
+	/// EIP-1559 transactions pay at most `max_priority_fee_per_gas`.
+	pub fn effective_priority_fee(&self, base_fee: Option<U256>) -> U256 {
+		let base_fee = base_fee.unwrap_or_else(U256::zero);
+		cmp::min(self.max_priority_fee_per_gas(), self.gas_price.saturating_sub(base_fee))
+	}
+
+	/// The message hash signed by the sender.
+	fn signing_hash(&self) -> H256 {
+		match self.typed {
+			Some(ref typed) => typed.signing_hash(&self.unsigned),
+			None => self.unsigned.hash(self.chain_id()),
+		}
+	}
+
 	/// Recovers the public key of the sender.
 	pub fn recover_public(&self) -> Result<Public, ethkey::Error> {
-		Ok(recover(&self.signature(), &self.unsigned.hash(self.chain_id()))?)
+		Ok(recover(&self.signature(), &self.signing_hash())?)
 	}
 
 	/// Verify basic signature params. Does not attempt sender recovery.
 	pub fn verify_basic(&self, check_low_s: bool, chain_id: Option<u64>, allow_empty_signature: bool) -> Result<(), error::Error> {
+		if let Some(ref typed) = self.typed {
+			return self.verify_typed_basic(typed, check_low_s, chain_id);
+		}
 		if check_low_s && !(allow_empty_signature && self.is_unsigned()) {
 			self.check_low_s()?;
 		}
@@ -402,6 +524,27 @@ impl UnverifiedTransaction {
 		};
 		Ok(())
 	}
+
+	/// Verify basic signature params of a typed transaction. EIP-86 empty signatures are never
+	/// allowed and the transaction always has to name the expected chain.
+	fn verify_typed_basic(&self, typed: &TypedFields, check_low_s: bool, chain_id: Option<u64>) -> Result<(), error::Error> {
+		if self.is_unsigned() || self.standard_v() > 1 {
+			return Err(ethkey::Error::InvalidSignature.into());
+		}
+		if check_low_s {
+			self.check_low_s()?;
+		}
+		if chain_id != Some(typed.chain_id) {
+			return Err(error::Error::InvalidChainId);
+		}
+		if typed.tx_type == TypedTxId::EIP1559 && typed.max_priority_fee_per_gas > self.gas_price {
+			return Err(error::Error::PriorityFeeAboveMaxFee {
+				max_fee: self.gas_price,
+				got: typed.max_priority_fee_per_gas,
+			});
+		}
+		Ok(())
+	}
 }
 
 /// A `UnverifiedTransaction` with successfully recovered `sender`.
@@ -0,0 +1,460 @@
+// Copyright 2015-2019 Parity Technologies (UK) Ltd.
+// This file is part of Parity Ethereum.
+
+// Parity Ethereum is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Ethereum is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Ethereum.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EIP-2718 typed transaction envelopes.
+//!
+//! A typed transaction is encoded as `type || payload`, where `type` is a single byte
+//! below `0x7f` and `payload` is an RLP list. Legacy transactions keep their plain RLP
+//! list encoding, which always starts with a byte `>= 0xc0`, so both can be told apart
+//! by looking at the first byte only. Within a block body typed transactions are
+//! embedded as RLP byte strings holding the envelope.
+
+use ethereum_types::{H256, Address, U256};
+use ethkey::{self, Secret};
+use hash::keccak;
+use heapsize::HeapSizeOf;
+use rlp::{self, RlpStream, Rlp, DecoderError};
+
+use transaction::{Action, Transaction, UnverifiedTransaction, SignedTransaction};
+
+type Bytes = Vec<u8>;
+
+/// EIP-2718 transaction type identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypedTxId {
+	/// Legacy transaction, encoded as a bare RLP list without a type prefix.
+	Legacy = 0x00,
+	/// EIP-2930 access list transaction.
+	AccessList = 0x01,
+	/// EIP-1559 dynamic fee transaction.
+	EIP1559 = 0x02,
+}
+
+impl TypedTxId {
+	/// Interpret the first byte of an EIP-2718 envelope.
+	pub fn from_byte(byte: u8) -> Option<TypedTxId> {
+		match byte {
+			0x01 => Some(TypedTxId::AccessList),
+			0x02 => Some(TypedTxId::EIP1559),
+			_ => None,
+		}
+	}
+}
+
+/// Single entry of an EIP-2930 access list.
+///
+/// Address and storage key lengths are enforced when decoding.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct AccessListItem {
+	/// Accessed account.
+	pub address: Address,
+	/// Accessed storage keys of that account.
+	pub storage_keys: Vec<H256>,
+}
+
+impl rlp::Encodable for AccessListItem {
+	fn rlp_append(&self, s: &mut RlpStream) {
+		s.begin_list(2);
+		s.append(&self.address);
+		s.append_list(&self.storage_keys);
+	}
+}
+
+impl rlp::Decodable for AccessListItem {
+	fn decode(d: &Rlp) -> Result<Self, DecoderError> {
+		if d.item_count()? != 2 {
+			return Err(DecoderError::RlpIncorrectListLen);
+		}
+		Ok(AccessListItem {
+			address: d.val_at(0)?,
+			storage_keys: d.list_at(1)?,
+		})
+	}
+}
+
+impl HeapSizeOf for AccessListItem {
+	fn heap_size_of_children(&self) -> usize {
+		self.storage_keys.heap_size_of_children()
+	}
+}
+
+/// EIP-2930 access list.
+pub type AccessList = Vec<AccessListItem>;
+
+/// Fields a typed transaction carries on top of the legacy `Transaction` ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TypedFields {
+	/// Transaction type, never `TypedTxId::Legacy`.
+	pub(crate) tx_type: TypedTxId,
+	/// Chain ID.
+	pub(crate) chain_id: u64,
+	/// Maximum fee per gas paid to the block author on top of the base fee. Only
+	/// meaningful for EIP-1559 transactions, whose `gas_price` is their max fee per gas.
+	pub(crate) max_priority_fee_per_gas: U256,
+	/// Accounts and storage keys the transaction plans to access.
+	pub(crate) access_list: AccessList,
+}
+
+impl TypedFields {
+	/// Number of RLP items in the payload of the given type, not counting the signature.
+	pub(crate) fn field_count(tx_type: TypedTxId) -> usize {
+		match tx_type {
+			TypedTxId::EIP1559 => 9,
+			_ => 8,
+		}
+	}
+
+	/// Append the unsigned fields to an already started RLP list.
+	fn rlp_append_fields(&self, unsigned: &Transaction, s: &mut RlpStream) {
+		s.append(&self.chain_id);
+		s.append(&unsigned.nonce);
+		if self.tx_type == TypedTxId::EIP1559 {
+			s.append(&self.max_priority_fee_per_gas);
+		}
+		s.append(&unsigned.gas_price);
+		s.append(&unsigned.gas);
+		s.append(&unsigned.action);
+		s.append(&unsigned.value);
+		s.append(&unsigned.data);
+		s.append_list(&self.access_list);
+	}
+
+	/// Decode the unsigned fields from the head of the payload list.
+	pub(crate) fn decode_fields(tx_type: TypedTxId, d: &Rlp) -> Result<(Transaction, TypedFields), DecoderError> {
+		// EIP-1559 inserts the priority fee before the max fee, which takes the place of the gas price.
+		let (max_priority_fee_per_gas, offset) = match tx_type {
+			TypedTxId::EIP1559 => (d.val_at(2)?, 1),
+			_ => (U256::zero(), 0),
+		};
+		let unsigned = Transaction {
+			nonce: d.val_at(1)?,
+			gas_price: d.val_at(2 + offset)?,
+			gas: d.val_at(3 + offset)?,
+			action: d.val_at(4 + offset)?,
+			value: d.val_at(5 + offset)?,
+			data: d.val_at(6 + offset)?,
+		};
+		let typed = TypedFields {
+			tx_type,
+			chain_id: d.val_at(0)?,
+			max_priority_fee_per_gas,
+			access_list: d.list_at(7 + offset)?,
+		};
+		Ok((unsigned, typed))
+	}
+
+	/// The message hash signed by the sender: `keccak(type || rlp(unsigned fields))`.
+	pub(crate) fn signing_hash(&self, unsigned: &Transaction) -> H256 {
+		let mut s = RlpStream::new_list(Self::field_count(self.tx_type));
+		self.rlp_append_fields(unsigned, &mut s);
+		keccak(envelope(self.tx_type, &s.out()))
+	}
+
+	/// Encode as `type || rlp([fields..., y_parity, r, s])`.
+	pub(crate) fn encode(&self, unsigned: &Transaction, y_parity: u64, r: &U256, s: &U256) -> Bytes {
+		let mut stream = RlpStream::new_list(Self::field_count(self.tx_type) + 3);
+		self.rlp_append_fields(unsigned, &mut stream);
+		stream.append(&y_parity);
+		stream.append(r);
+		stream.append(s);
+		envelope(self.tx_type, &stream.out())
+	}
+
+	/// Heap size of the typed fields.
+	pub(crate) fn heap_size_of_children(&self) -> usize {
+		self.access_list.heap_size_of_children()
+	}
+}
+
+/// Prefix an RLP payload with its EIP-2718 type byte.
+fn envelope(tx_type: TypedTxId, payload: &[u8]) -> Bytes {
+	let mut out = Vec::with_capacity(payload.len() + 1);
+	out.push(tx_type as u8);
+	out.extend_from_slice(payload);
+	out
+}
+
+/// Signs the unsigned parts of a typed transaction as coming from the owner of `secret`.
+fn sign(unsigned: Transaction, typed: TypedFields, secret: &Secret) -> SignedTransaction {
+	let sig = ethkey::sign(secret, &typed.signing_hash(&unsigned))
+		.expect("data is valid and context has signing capabilities; qed");
+	SignedTransaction::new(UnverifiedTransaction::typed_with_signature(unsigned, typed, sig))
+		.expect("secret is valid so it's recoverable")
+}
+
+/// EIP-2930 access list transaction.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct AccessListTransaction {
+	/// Chain ID.
+	pub chain_id: u64,
+	/// Nonce.
+	pub nonce: U256,
+	/// Gas price.
+	pub gas_price: U256,
+	/// Gas paid up front for transaction execution.
+	pub gas: U256,
+	/// Action, can be either call or contract create.
+	pub action: Action,
+	/// Transfered value.
+	pub value: U256,
+	/// Transaction data.
+	pub data: Bytes,
+	/// Accounts and storage keys the transaction plans to access.
+	pub access_list: AccessList,
+}
+
+impl AccessListTransaction {
+	/// Signs the transaction with the given secret.
+	pub fn sign(self, secret: &Secret) -> SignedTransaction {
+		let unsigned = Transaction {
+			nonce: self.nonce,
+			gas_price: self.gas_price,
+			gas: self.gas,
+			action: self.action,
+			value: self.value,
+			data: self.data,
+		};
+		let typed = TypedFields {
+			tx_type: TypedTxId::AccessList,
+			chain_id: self.chain_id,
+			max_priority_fee_per_gas: U256::zero(),
+			access_list: self.access_list,
+		};
+		sign(unsigned, typed, secret)
+	}
+}
+
+/// EIP-1559 dynamic fee transaction.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct EIP1559Transaction {
+	/// Chain ID.
+	pub chain_id: u64,
+	/// Nonce.
+	pub nonce: U256,
+	/// Maximum fee per gas paid to the block author on top of the base fee.
+	pub max_priority_fee_per_gas: U256,
+	/// Maximum total fee per gas, base fee included.
+	pub max_fee_per_gas: U256,
+	/// Gas paid up front for transaction execution.
+	pub gas: U256,
+	/// Action, can be either call or contract create.
+	pub action: Action,
+	/// Transfered value.
+	pub value: U256,
+	/// Transaction data.
+	pub data: Bytes,
+	/// Accounts and storage keys the transaction plans to access.
+	pub access_list: AccessList,
+}
+
+impl EIP1559Transaction {
+	/// Signs the transaction with the given secret.
+	pub fn sign(self, secret: &Secret) -> SignedTransaction {
+		let unsigned = Transaction {
+			nonce: self.nonce,
+			gas_price: self.max_fee_per_gas,
+			gas: self.gas,
+			action: self.action,
+			value: self.value,
+			data: self.data,
+		};
+		let typed = TypedFields {
+			tx_type: TypedTxId::EIP1559,
+			chain_id: self.chain_id,
+			max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+			access_list: self.access_list,
+		};
+		sign(unsigned, typed, secret)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ethkey::{Random, Generator, public_to_address};
+	use transaction::error;
+
+	fn access_list() -> AccessList {
+		vec![
+			AccessListItem {
+				address: Address::from(0x01),
+				storage_keys: vec![H256::from(0x01), H256::from(0x02)],
+			},
+			AccessListItem {
+				address: Address::from(0x02),
+				storage_keys: vec![],
+			},
+		]
+	}
+
+	fn access_list_tx() -> AccessListTransaction {
+		AccessListTransaction {
+			chain_id: 1,
+			nonce: U256::from(42),
+			gas_price: U256::from(3000),
+			gas: U256::from(50_000),
+			action: Action::Call(Address::from(0x69)),
+			value: U256::from(1),
+			data: b"Hello!".to_vec(),
+			access_list: access_list(),
+		}
+	}
+
+	fn eip1559_tx() -> EIP1559Transaction {
+		EIP1559Transaction {
+			chain_id: 1,
+			nonce: U256::from(42),
+			max_priority_fee_per_gas: U256::from(2),
+			max_fee_per_gas: U256::from(3000),
+			gas: U256::from(50_000),
+			action: Action::Create,
+			value: U256::from(1),
+			data: b"Hello!".to_vec(),
+			access_list: access_list(),
+		}
+	}
+
+	#[test]
+	fn legacy_roundtrip() {
+		let key = Random.generate().unwrap();
+		let tx: UnverifiedTransaction = Transaction {
+			action: Action::Create,
+			nonce: U256::from(42),
+			gas_price: U256::from(3000),
+			gas: U256::from(50_000),
+			value: U256::from(1),
+			data: b"Hello!".to_vec()
+		}.sign(&key.secret(), Some(1)).into();
+
+		let encoded = tx.encode_raw();
+		assert_eq!(encoded, rlp::encode(&tx));
+
+		let decoded = UnverifiedTransaction::decode_raw(&encoded).unwrap();
+		assert_eq!(decoded.tx_type(), TypedTxId::Legacy);
+		assert_eq!(decoded.hash(), keccak(&encoded));
+		assert_eq!(decoded, tx);
+		assert_eq!(public_to_address(&decoded.recover_public().unwrap()), key.address());
+		assert!(decoded.verify_basic(true, Some(1), false).is_ok());
+	}
+
+	#[test]
+	fn access_list_roundtrip() {
+		let key = Random.generate().unwrap();
+		let tx: UnverifiedTransaction = access_list_tx().sign(&key.secret()).into();
+
+		let encoded = tx.encode_raw();
+		assert_eq!(encoded[0], 0x01);
+
+		let decoded = UnverifiedTransaction::decode_raw(&encoded).unwrap();
+		assert_eq!(decoded.tx_type(), TypedTxId::AccessList);
+		assert_eq!(decoded.hash(), keccak(&encoded));
+		assert_eq!(decoded.chain_id(), Some(1));
+		assert_eq!(decoded.encode_raw(), encoded);
+		assert_eq!(decoded, tx);
+		assert_eq!(public_to_address(&decoded.recover_public().unwrap()), key.address());
+		assert!(decoded.verify_basic(true, Some(1), false).is_ok());
+	}
+
+	#[test]
+	fn eip1559_roundtrip() {
+		let key = Random.generate().unwrap();
+		let tx: UnverifiedTransaction = eip1559_tx().sign(&key.secret()).into();
+
+		let encoded = tx.encode_raw();
+		assert_eq!(encoded[0], 0x02);
+
+		let decoded = UnverifiedTransaction::decode_raw(&encoded).unwrap();
+		assert_eq!(decoded.tx_type(), TypedTxId::EIP1559);
+		assert_eq!(decoded.hash(), keccak(&encoded));
+		assert_eq!(decoded.max_priority_fee_per_gas(), U256::from(2));
+		assert_eq!(decoded.gas_price, U256::from(3000));
+		assert_eq!(decoded.encode_raw(), encoded);
+		assert_eq!(decoded, tx);
+		assert_eq!(public_to_address(&decoded.recover_public().unwrap()), key.address());
+		assert!(decoded.verify_basic(true, Some(1), false).is_ok());
+	}
+
+	#[test]
+	fn should_embed_typed_transactions_as_byte_strings() {
+		let key = Random.generate().unwrap();
+		let txs: Vec<UnverifiedTransaction> = vec![
+			Transaction::default().sign(&key.secret(), Some(1)).into(),
+			access_list_tx().sign(&key.secret()).into(),
+			eip1559_tx().sign(&key.secret()).into(),
+		];
+
+		let encoded = rlp::encode_list::<UnverifiedTransaction, _>(&txs);
+		let list = Rlp::new(&encoded);
+		assert!(list.at(0).unwrap().is_list());
+		assert!(list.at(1).unwrap().is_data());
+		assert!(list.at(2).unwrap().is_data());
+		for (item, tx) in list.iter().zip(&txs) {
+			assert_eq!(UnverifiedTransaction::hash_rlp(&item), Ok(tx.hash()));
+		}
+
+		assert_eq!(rlp::decode_list::<UnverifiedTransaction>(&encoded), txs);
+	}
+
+	#[test]
+	fn should_reject_unknown_type_and_trailing_bytes() {
+		let key = Random.generate().unwrap();
+		let mut encoded = eip1559_tx().sign(&key.secret()).encode_raw();
+
+		encoded[0] = 0x03;
+		assert_eq!(UnverifiedTransaction::decode_raw(&encoded), Err(DecoderError::Custom("Unknown transaction type")));
+
+		encoded[0] = 0x02;
+		encoded.push(0x00);
+		assert_eq!(UnverifiedTransaction::decode_raw(&encoded), Err(DecoderError::RlpInconsistentLengthAndData));
+
+		assert_eq!(UnverifiedTransaction::decode_raw(&[]), Err(DecoderError::RlpIsTooShort));
+	}
+
+	#[test]
+	fn should_reject_wrong_chain_id() {
+		let key = Random.generate().unwrap();
+		let tx = access_list_tx().sign(&key.secret());
+
+		assert_eq!(tx.verify_basic(true, Some(2), false), Err(error::Error::InvalidChainId));
+		assert_eq!(tx.verify_basic(true, None, false), Err(error::Error::InvalidChainId));
+	}
+
+	#[test]
+	fn should_reject_priority_fee_above_max_fee() {
+		let key = Random.generate().unwrap();
+		let mut unsigned = eip1559_tx();
+		unsigned.max_priority_fee_per_gas = U256::from(3001);
+		let tx = unsigned.sign(&key.secret());
+
+		assert_eq!(tx.verify_basic(true, Some(1), false), Err(error::Error::PriorityFeeAboveMaxFee {
+			max_fee: U256::from(3000),
+			got: U256::from(3001),
+		}));
+	}
+
+	#[test]
+	fn should_compute_effective_priority_fee() {
+		let key = Random.generate().unwrap();
+		let access_list = access_list_tx().sign(&key.secret());
+		let eip1559 = eip1559_tx().sign(&key.secret());
+
+		assert_eq!(access_list.effective_priority_fee(None), U256::from(3000));
+		assert_eq!(access_list.effective_priority_fee(Some(1000.into())), U256::from(2000));
+		assert_eq!(eip1559.effective_priority_fee(None), U256::from(2));
+		assert_eq!(eip1559.effective_priority_fee(Some(2999.into())), U256::from(1));
+		assert_eq!(eip1559.effective_priority_fee(Some(5000.into())), U256::zero());
+	}
+}
@@ -99,17 +99,35 @@ impl<'a> HeaderView<'a> {
 	pub fn extra_data(&self) -> Bytes { self.rlp.val_at(12) }
 
 	/// Returns a vector of post-RLP-encoded seal fields.
-	pub fn seal(&self) -> Vec<Bytes> {
+	///
+	/// Headers of blocks at or after `eip1559_transition` end with the base fee, which is
+	/// not part of the seal.
+	pub fn seal(&self, eip1559_transition: BlockNumber) -> Vec<Bytes> {
+		let mut seal_end = self.rlp.item_count();
+		if self.number() >= eip1559_transition {
+			seal_end -= 1;
+		}
+
 		let mut seal = vec![];
-		for i in 13..self.rlp.item_count() {
+		for i in 13..seal_end {
 			seal.push(self.rlp.at(i).as_raw().to_vec());
 		}
 		seal
 	}
 
+	/// Returns the EIP-1559 base fee, which headers of blocks at or after `eip1559_transition`
+	/// end with.
+	pub fn base_fee_per_gas(&self, eip1559_transition: BlockNumber) -> Option<U256> {
+		if self.number() >= eip1559_transition {
+			Some(self.rlp.val_at(self.rlp.item_count() - 1))
+		} else {
+			None
+		}
+	}
+
 	/// Returns a vector of seal fields (RLP-decoded).
-	pub fn decode_seal(&self) -> Result<Vec<Bytes>, rlp::DecoderError> {
-		let seal = self.seal();
+	pub fn decode_seal(&self, eip1559_transition: BlockNumber) -> Result<Vec<Bytes>, rlp::DecoderError> {
+		let seal = self.seal(eip1559_transition);
 		seal.into_iter()
 			.map(|s| rlp::Rlp::new(&s).data().map(|x| x.to_vec()))
 			.collect()
@@ -122,6 +140,7 @@ mod tests {
 	use rustc_hex::FromHex;
 	use ethereum_types::Bloom;
 	use super::HeaderView;
+	use BlockNumber;
 
 	#[test]
 	fn test_header_view() {
@@ -145,6 +164,6 @@ mod tests {
 		assert_eq!(view.gas_used(), 0x524d.into());
 		assert_eq!(view.timestamp(), 0x56_8e_93_2a);
 		assert_eq!(view.extra_data(), vec![] as Vec<u8>);
-		assert_eq!(view.seal(), vec![mix_hash, nonce]);
+		assert_eq!(view.seal(BlockNumber::max_value()), vec![mix_hash, nonce]);
 	}
 }
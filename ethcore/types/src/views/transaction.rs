@@ -17,10 +17,13 @@
 //! View onto transaction rlp
 use bytes::Bytes;
 use ethereum_types::{H256, U256};
-use hash::keccak;
+use transaction::UnverifiedTransaction;
 use super::ViewRlp;
 
 /// View onto transaction rlp.
+///
+/// Only legacy transactions are laid out as a plain RLP list, the field accessors
+/// don't support EIP-2718 typed transactions.
 pub struct TransactionView<'a> {
 	rlp: ViewRlp<'a>
 }
@@ -55,7 +58,7 @@ impl<'a> TransactionView<'a> {
 
 	/// Returns transaction hash.
 	pub fn hash(&self) -> H256 {
-		keccak(self.rlp.as_raw())
+		self.rlp.decode_with(UnverifiedTransaction::hash_rlp)
 	}
 
 	/// Get the nonce field of the transaction.
@@ -77,6 +77,11 @@ impl<'a, 'view> ViewRlp<'a> where 'a : 'view {
 		self.expect_valid_rlp(self.rlp.as_val())
 	}
 
+	/// Returns value decoded from this rlp by `decode`, panics if rlp not valid
+	pub fn decode_with<T, F>(&self, decode: F) -> T where F: FnOnce(&Rlp<'a>) -> Result<T, DecoderError> {
+		self.expect_valid_rlp(decode(&self.rlp))
+	}
+
 	/// Returns decoded value at the given index, panics not present or valid at that index
 	pub fn val_at<T>(&self, index: usize) -> T where T : Decodable {
 		self.expect_valid_rlp(self.rlp.val_at(index))
@@ -94,6 +94,10 @@ pub struct Params {
 	/// See `CommonParams` docs.
 	pub eip1014_transition: Option<Uint>,
 	/// See `CommonParams` docs.
+	pub eip2930_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	pub eip1559_transition: Option<Uint>,
+	/// See `CommonParams` docs.
 	pub dust_protection_transition: Option<Uint>,
 	/// See `CommonParams` docs.
 	pub nonce_cap_increment: Option<Uint>,
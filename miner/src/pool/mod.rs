@@ -119,6 +119,12 @@ pub trait ScoredTransaction {
 
 	/// Gets transaction nonce.
 	fn nonce(&self) -> U256;
+
+	/// Gets transaction max priority fee per gas, the gas price for non EIP-1559 transactions.
+	fn max_priority_fee_per_gas(&self) -> U256;
+
+	/// Gets the fee per gas paid to the block author given the block's base fee.
+	fn effective_priority_fee(&self, base_fee: Option<U256>) -> U256;
 }
 
 /// Verified transaction stored in the pool.
@@ -198,6 +204,16 @@ impl ScoredTransaction for VerifiedTransaction {
 	fn nonce(&self) -> U256 {
 		self.transaction.nonce
 	}
+
+	/// Gets transaction max priority fee per gas.
+	fn max_priority_fee_per_gas(&self) -> U256 {
+		self.transaction.max_priority_fee_per_gas()
+	}
+
+	/// Gets the fee per gas paid to the block author.
+	fn effective_priority_fee(&self, base_fee: Option<U256>) -> U256 {
+		self.transaction.effective_priority_fee(base_fee)
+	}
 }
 
 /// Pool transactions status
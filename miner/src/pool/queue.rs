@@ -216,7 +216,7 @@ impl TransactionQueue {
 		let max_count = limits.max_count;
 		TransactionQueue {
 			insertion_id: Default::default(),
-			pool: RwLock::new(txpool::Pool::new(Default::default(), scoring::NonceAndGasPrice(strategy), limits)),
+			pool: RwLock::new(txpool::Pool::new(Default::default(), scoring::NonceAndGasPrice::new(strategy), limits)),
 			options: RwLock::new(verification_options),
 			cached_pending: RwLock::new(CachedPending::none()),
 			recently_rejected: RecentlyRejected::new(cmp::max(MIN_REJECTED_CACHE_SIZE, max_count / 4)),
@@ -529,10 +529,27 @@ impl TransactionQueue {
 	pub fn penalize<'a, T: IntoIterator<Item = &'a Address>>(&self, senders: T) {
 		let mut pool = self.pool.write();
 		for sender in senders {
-			pool.update_scores(sender, ());
+			pool.update_scores(sender, scoring::ScoringEvent::Penalize);
 		}
 	}
 
+	/// Set the base fee of the next block, rescoring all transactions if it changed.
+	pub fn set_block_base_fee(&self, base_fee: Option<U256>) {
+		{
+			let mut pool = self.pool.write();
+			if !pool.scoring().set_block_base_fee(base_fee) {
+				return;
+			}
+
+			let senders = pool.senders().cloned().collect::<Vec<_>>();
+			for sender in &senders {
+				pool.update_scores(sender, scoring::ScoringEvent::BlockBaseFeeChanged);
+			}
+		}
+
+		self.cached_pending.write().clear();
+	}
+
 	/// Returns gas price of currently the worst transaction in the pool.
 	pub fn current_worst_gas_price(&self) -> U256 {
 		match self.pool.read().worst_transaction() {
@@ -129,7 +129,7 @@ mod tests {
 
 	#[test]
 	fn should_always_accept_local_transactions_unless_same_sender_and_nonce() {
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let client = TestClient::new().with_nonce(1);
 		let replace = ReplaceByScoreAndReadiness::new(scoring, client);
 
@@ -182,7 +182,7 @@ mod tests {
 
 	#[test]
 	fn should_replace_same_sender_by_nonce() {
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let client = TestClient::new().with_nonce(1);
 		let replace = ReplaceByScoreAndReadiness::new(scoring, client);
 
@@ -225,7 +225,7 @@ mod tests {
 	#[test]
 	fn should_replace_different_sender_by_priority_and_gas_price() {
 		// given
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let client = TestClient::new().with_nonce(0);
 		let replace = ReplaceByScoreAndReadiness::new(scoring, client);
 
@@ -278,7 +278,7 @@ mod tests {
 
 	#[test]
 	fn should_not_replace_ready_transaction_with_future_transaction() {
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let client = TestClient::new().with_nonce(1);
 		let replace = ReplaceByScoreAndReadiness::new(scoring, client);
 
@@ -304,7 +304,7 @@ mod tests {
 
 	#[test]
 	fn should_compute_readiness_with_pooled_transactions_from_the_same_sender_as_the_existing_transaction() {
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let client = TestClient::new().with_nonce(1);
 		let replace = ReplaceByScoreAndReadiness::new(scoring, client);
 
@@ -359,7 +359,7 @@ mod tests {
 
 	#[test]
 	fn should_compute_readiness_with_pooled_transactions_from_the_same_sender_as_the_new_transaction() {
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let client = TestClient::new().with_nonce(1);
 		let replace = ReplaceByScoreAndReadiness::new(scoring, client);
 
@@ -26,10 +26,16 @@
 //! Transactions between senders are prioritized using `gas price`. Higher `gas price`
 //! yields more profits for miners. Additionally we prioritize transactions that originate
 //! from our local node (own transactions).
+//!
+//! Once blocks carry an EIP-1559 base fee, the miner only receives the part of the
+//! `gas price` above it, capped by the transaction's max priority fee, so that
+//! effective priority fee is used in place of the `gas price`.
 
 use std::cmp;
+use std::sync::Arc;
 
 use ethereum_types::U256;
+use parking_lot::RwLock;
 use txpool::{self, scoring};
 use super::{verifier, PrioritizationStrategy, VerifiedTransaction, ScoredTransaction};
 
@@ -43,14 +49,65 @@ fn bump_gas_price(old_gp: U256) -> U256 {
 	old_gp.saturating_add(old_gp >> GAS_PRICE_BUMP_SHIFT)
 }
 
+/// Calculate the score of a transaction given the base fee of the next block.
+fn effective_score<P: ScoredTransaction>(tx: &P, base_fee: Option<U256>) -> U256 {
+	let boost = match tx.priority() {
+		super::Priority::Local => 15,
+		super::Priority::Retracted => 10,
+		super::Priority::Regular => 0,
+	};
+	tx.effective_priority_fee(base_fee) << boost
+}
+
+/// Events the pool may be notified of through `Pool::update_scores`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoringEvent {
+	/// Lower the priority of the sender's non-local transactions.
+	Penalize,
+	/// The base fee of the next block changed, scores have to be recalculated.
+	BlockBaseFeeChanged,
+}
+
 /// Simple, gas-price based scoring for transactions.
 ///
+/// Transactions are scored by the effective priority fee they pay on top of the
+/// base fee of the next block, which is just their gas price before EIP-1559.
+///
 /// NOTE: Currently penalization does not apply to new transactions that enter the pool.
 /// We might want to store penalization status in some persistent state.
 #[derive(Debug, Clone)]
-pub struct NonceAndGasPrice(pub PrioritizationStrategy);
+pub struct NonceAndGasPrice {
+	/// Prioritization strategy.
+	pub strategy: PrioritizationStrategy,
+	/// Base fee of the next block, shared between clones of the scoring.
+	block_base_fee: Arc<RwLock<Option<U256>>>,
+}
 
 impl NonceAndGasPrice {
+	/// Create new scoring with given prioritization strategy.
+	pub fn new(strategy: PrioritizationStrategy) -> Self {
+		NonceAndGasPrice {
+			strategy,
+			block_base_fee: Default::default(),
+		}
+	}
+
+	/// Base fee of the next block, if any.
+	pub fn block_base_fee(&self) -> Option<U256> {
+		*self.block_base_fee.read()
+	}
+
+	/// Set the base fee of the next block. Returns `true` if it changed, in which case
+	/// all scores should be recalculated with `ScoringEvent::BlockBaseFeeChanged`.
+	pub fn set_block_base_fee(&self, base_fee: Option<U256>) -> bool {
+		let mut block_base_fee = self.block_base_fee.write();
+		if *block_base_fee == base_fee {
+			return false;
+		}
+		*block_base_fee = base_fee;
+		true
+	}
+
 	/// Decide if the transaction should even be considered into the pool (if the pool is full).
 	///
 	/// Used by Verifier to quickly reject transactions that don't have any chance to get into the pool later on,
@@ -63,13 +120,14 @@ impl NonceAndGasPrice {
 			return true
 		}
 
-		&old.transaction.gas_price > new.gas_price()
+		let base_fee = self.block_base_fee();
+		old.effective_priority_fee(base_fee) > new.effective_priority_fee(base_fee)
 	}
 }
 
 impl<P> txpool::Scoring<P> for NonceAndGasPrice where P: ScoredTransaction + txpool::VerifiedTransaction {
 	type Score = U256;
-	type Event = ();
+	type Event = ScoringEvent;
 
 	fn compare(&self, old: &P, other: &P) -> cmp::Ordering {
 		old.nonce().cmp(&other.nonce())
@@ -84,14 +142,16 @@ impl<P> txpool::Scoring<P> for NonceAndGasPrice where P: ScoredTransaction + txp
 		let new_gp = new.gas_price();
 
 		let min_required_gp = bump_gas_price(*old_gp);
+		let min_required_priority_fee = bump_gas_price(old.max_priority_fee_per_gas());
 
-		match min_required_gp.cmp(&new_gp) {
-			cmp::Ordering::Greater => scoring::Choice::RejectNew,
-			_ => scoring::Choice::ReplaceOld,
+		if min_required_gp > *new_gp || min_required_priority_fee > new.max_priority_fee_per_gas() {
+			scoring::Choice::RejectNew
+		} else {
+			scoring::Choice::ReplaceOld
 		}
 	}
 
-	fn update_scores(&self, txs: &[txpool::Transaction<P>], scores: &mut [U256], change: scoring::Change) {
+	fn update_scores(&self, txs: &[txpool::Transaction<P>], scores: &mut [U256], change: scoring::Change<ScoringEvent>) {
 		use self::scoring::Change;
 
 		match change {
@@ -101,17 +161,10 @@ impl<P> txpool::Scoring<P> for NonceAndGasPrice where P: ScoredTransaction + txp
 				assert!(i < txs.len());
 				assert!(i < scores.len());
 
-				scores[i] = *txs[i].transaction.gas_price();
-				let boost = match txs[i].priority() {
-					super::Priority::Local => 15,
-					super::Priority::Retracted => 10,
-					super::Priority::Regular => 0,
-				};
-				scores[i] = scores[i] << boost;
+				scores[i] = effective_score(&*txs[i].transaction, self.block_base_fee());
 			},
-			// We are only sending an event in case of penalization.
-			// So just lower the priority of all non-local transactions.
-			Change::Event(_) => {
+			// Lower the priority of all non-local transactions.
+			Change::Event(ScoringEvent::Penalize) => {
 				for (score, tx) in scores.iter_mut().zip(txs) {
 					// Never penalize local transactions.
 					if !tx.priority().is_local() {
@@ -119,6 +172,13 @@ impl<P> txpool::Scoring<P> for NonceAndGasPrice where P: ScoredTransaction + txp
 					}
 				}
 			},
+			// Recalculate all scores, note that this drops any earlier penalization.
+			Change::Event(ScoringEvent::BlockBaseFeeChanged) => {
+				let base_fee = self.block_base_fee();
+				for (score, tx) in scores.iter_mut().zip(txs) {
+					*score = effective_score(&*tx.transaction, base_fee);
+				}
+			},
 		}
 	}
 
@@ -131,14 +191,14 @@ impl<P> txpool::Scoring<P> for NonceAndGasPrice where P: ScoredTransaction + txp
 mod tests {
 	use super::*;
 
-	use std::sync::Arc;
+	use ethkey::{Random, Generator};
 	use pool::tests::tx::{Tx, TxExt};
 	use txpool::Scoring;
 
 	#[test]
 	fn should_calculate_score_correctly() {
 		// given
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let (tx1, tx2, tx3) = Tx::default().signed_triple();
 		let transactions = vec![tx1, tx2, tx3].into_iter().enumerate().map(|(i, tx)| {
 			let mut verified = tx.verified();
@@ -184,7 +244,37 @@ mod tests {
 		assert_eq!(scores, vec![32768.into(), 1024.into(), 1.into()]);
 
 		// Check penalization
-		scoring.update_scores(&transactions, &mut *scores, scoring::Change::Event(()));
+		scoring.update_scores(&transactions, &mut *scores, scoring::Change::Event(ScoringEvent::Penalize));
 		assert_eq!(scores, vec![32768.into(), 128.into(), 0.into()]);
+
+		// Base fee change recalculates scores
+		assert!(scoring.set_block_base_fee(Some(0.into())));
+		assert!(!scoring.set_block_base_fee(Some(0.into())));
+		scoring.update_scores(&transactions, &mut *scores, scoring::Change::Event(ScoringEvent::BlockBaseFeeChanged));
+		assert_eq!(scores, vec![32768.into(), 1024.into(), 1.into()]);
+	}
+
+	#[test]
+	fn should_score_by_effective_priority_fee() {
+		// given
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
+		let keypair = Random.generate().unwrap();
+		let legacy = Tx::gas_price(12).signed().verified();
+		let eip1559 = Tx::gas_price(20).eip1559_signed(5, &keypair).verified();
+		let transactions = vec![legacy, eip1559].into_iter().map(|verified| txpool::Transaction {
+			insertion_id: 0,
+			transaction: Arc::new(verified),
+		}).collect::<Vec<_>>();
+		let mut scores = vec![U256::from(0), 0.into()];
+
+		// without a base fee the priority fee is capped by the max priority fee
+		scoring.update_scores(&transactions, &mut *scores, scoring::Change::InsertedAt(0));
+		scoring.update_scores(&transactions, &mut *scores, scoring::Change::InsertedAt(1));
+		assert_eq!(scores, vec![12.into(), 5.into()]);
+
+		// the base fee is deducted from the gas price
+		scoring.set_block_base_fee(Some(10.into()));
+		scoring.update_scores(&transactions, &mut *scores, scoring::Change::Event(ScoringEvent::BlockBaseFeeChanged));
+		assert_eq!(scores, vec![2.into(), 5.into()]);
 	}
 }
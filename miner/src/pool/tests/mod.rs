@@ -1074,3 +1074,59 @@ fn should_not_reject_early_in_case_gas_price_is_less_than_min_effective() {
 	assert_eq!(txq.status().status.transaction_count, 2);
 	assert!(client.was_verification_triggered());
 }
+
+#[test]
+fn should_import_typed_transactions() {
+	use ethkey::{Random, Generator};
+
+	// given
+	let txq = new_queue();
+	let keypair = Random.generate().unwrap();
+	let tx1 = Tx::gas_price(2).access_list_signed(&keypair);
+	let mut tx = Tx::gas_price(2);
+	tx.nonce += 1;
+	let tx2 = tx.eip1559_signed(1, &keypair);
+	let (hash1, hash2) = (tx1.hash(), tx2.hash());
+	let client = TestClient::new().with_balance(1_000_000);
+
+	// when
+	let res = txq.import(client.clone(), vec![tx1, tx2].unverified());
+
+	// then
+	assert_eq!(res, vec![Ok(()), Ok(())]);
+	assert_eq!(txq.status().status.transaction_count, 2);
+	let top = txq.pending(client, PendingSettings::all_prioritized(0, 0));
+	assert_eq!(top[0].hash, hash1);
+	assert_eq!(top[1].hash, hash2);
+}
+
+#[test]
+fn should_order_transactions_by_effective_priority_fee() {
+	use ethkey::{Random, Generator};
+
+	// given
+	let txq = new_queue();
+	let legacy = Tx::gas_price(12).signed();
+	let eip1559 = Tx::gas_price(20).eip1559_signed(5, &Random.generate().unwrap());
+	let (legacy_hash, eip1559_hash) = (legacy.hash(), eip1559.hash());
+	let client = TestClient::new().with_balance(1_000_000);
+	let res = txq.import(client.clone(), vec![legacy, eip1559].unverified());
+	assert_eq!(res, vec![Ok(()), Ok(())]);
+
+	// when
+	let top = txq.pending(client.clone(), PendingSettings::all_prioritized(0, 0));
+
+	// then
+	// without a base fee the EIP-1559 transaction only pays its priority fee
+	assert_eq!(top[0].hash, legacy_hash);
+	assert_eq!(top[1].hash, eip1559_hash);
+
+	// when
+	txq.set_block_base_fee(Some(10.into()));
+	let top = txq.pending(client, PendingSettings::all_prioritized(0, 0));
+
+	// then
+	// the legacy transaction only pays 2 above the base fee
+	assert_eq!(top[0].hash, eip1559_hash);
+	assert_eq!(top[1].hash, legacy_hash);
+}
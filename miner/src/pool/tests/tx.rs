@@ -15,9 +15,9 @@
 // along with Parity Ethereum.  If not, see <http://www.gnu.org/licenses/>.
 
 use ethereum_types::{U256, H256};
-use ethkey::{Random, Generator};
+use ethkey::{Random, Generator, KeyPair};
 use rustc_hex::FromHex;
-use types::transaction::{self, Transaction, SignedTransaction, UnverifiedTransaction};
+use types::transaction::{self, Transaction, SignedTransaction, UnverifiedTransaction, AccessListTransaction, EIP1559Transaction};
 
 use pool::{verifier, VerifiedTransaction};
 
@@ -76,6 +76,35 @@ impl Tx {
 		(tx1, tx2)
 	}
 
+	pub fn access_list_signed(self, keypair: &KeyPair) -> SignedTransaction {
+		let unsigned = self.unsigned();
+		AccessListTransaction {
+			chain_id: 1,
+			nonce: unsigned.nonce,
+			gas_price: unsigned.gas_price,
+			gas: unsigned.gas,
+			action: unsigned.action,
+			value: unsigned.value,
+			data: unsigned.data,
+			access_list: Vec::new(),
+		}.sign(keypair.secret())
+	}
+
+	pub fn eip1559_signed(self, max_priority_fee_per_gas: u64, keypair: &KeyPair) -> SignedTransaction {
+		let unsigned = self.unsigned();
+		EIP1559Transaction {
+			chain_id: 1,
+			nonce: unsigned.nonce,
+			max_priority_fee_per_gas: max_priority_fee_per_gas.into(),
+			max_fee_per_gas: unsigned.gas_price,
+			gas: unsigned.gas,
+			action: unsigned.action,
+			value: unsigned.value,
+			data: unsigned.data,
+			access_list: Vec::new(),
+		}.sign(keypair.secret())
+	}
+
 	pub fn unsigned(self) -> Transaction {
 		Transaction {
 			action: transaction::Action::Create,
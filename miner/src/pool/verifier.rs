@@ -97,6 +97,15 @@ impl Transaction {
 		}
 	}
 
+	/// Return the fee per gas paid to the block author given the block's base fee
+	pub fn effective_priority_fee(&self, base_fee: Option<U256>) -> U256 {
+		match *self {
+			Transaction::Unverified(ref tx) => tx.effective_priority_fee(base_fee),
+			Transaction::Retracted(ref tx) => tx.effective_priority_fee(base_fee),
+			Transaction::Local(ref tx) => tx.effective_priority_fee(base_fee),
+		}
+	}
+
 	fn gas(&self) -> &U256 {
 		match *self {
 			Transaction::Unverified(ref tx) => &tx.gas,
@@ -224,16 +233,19 @@ impl<C: Client> txpool::Verifier<Transaction> for Verifier<C, ::pool::scoring::N
 
 			if let Some((ref scoring, ref vtx)) = self.transaction_to_replace {
 				if scoring.should_reject_early(vtx, &tx) {
+					let base_fee = scoring.block_base_fee();
+					let minimal = vtx.transaction.effective_priority_fee(base_fee);
+					let got = tx.effective_priority_fee(base_fee);
 					trace!(
 						target: "txqueue",
-						"[{:?}] Rejected tx early, cause it doesn't have any chance to get to the pool: (gas price: {} < {})",
+						"[{:?}] Rejected tx early, cause it doesn't have any chance to get to the pool: (priority fee: {} < {})",
 						hash,
-						tx.gas_price(),
-						vtx.transaction.gas_price,
+						got,
+						minimal,
 					);
 					bail!(transaction::Error::InsufficientGasPrice {
-						minimal: vtx.transaction.gas_price,
-						got: *tx.gas_price(),
+						minimal,
+						got,
 					});
 				}
 			}
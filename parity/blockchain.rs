@@ -233,6 +233,8 @@ fn execute_import_light(cmd: ImportBlockchain) -> Result<(), String> {
 	let service = LightClientService::start(config, &spec, fetch, db, cache)
 		.map_err(|e| format!("Failed to start client: {}", e))?;
 
+	let eip1559_transition = spec.params().eip1559_transition;
+
 	// free up the spec in memory.
 	drop(spec);
 
@@ -262,7 +264,8 @@ fn execute_import_light(cmd: ImportBlockchain) -> Result<(), String> {
 	let do_import = |bytes: Vec<u8>| {
 		while client.queue_info().is_full() { sleep(Duration::from_secs(1)); }
 
-		let header: ::types::header::Header = ::rlp::Rlp::new(&bytes).val_at(0)
+		let header = ::rlp::Rlp::new(&bytes).at(0)
+			.and_then(|rlp| ::types::header::Header::decode_rlp(&rlp, eip1559_transition))
 			.map_err(|e| format!("Bad block: {}", e))?;
 
 		if client.best_block_header().number() >= header.number() { return Ok(()) }
@@ -401,6 +404,8 @@ fn execute_import(cmd: ImportBlockchain) -> Result<(), String> {
 		Default::default(),
 	).map_err(|e| format!("Client service error: {:?}", e))?;
 
+	let eip1559_transition = spec.params().eip1559_transition;
+
 	// free up the spec in memory.
 	drop(spec);
 
@@ -441,7 +446,7 @@ fn execute_import(cmd: ImportBlockchain) -> Result<(), String> {
 	service.register_io_handler(informant).map_err(|_| "Unable to register informant handler".to_owned())?;
 
 	let do_import = |bytes| {
-		let block = Unverified::from_rlp(bytes).map_err(|_| "Invalid block rlp")?;
+		let block = Unverified::from_rlp(bytes, eip1559_transition).map_err(|_| "Invalid block rlp")?;
 		while client.queue_info().is_full() { sleep(Duration::from_secs(1)); }
 		match client.import_block(block) {
 			Err(EthcoreError(EthcoreErrorKind::Import(ImportErrorKind::AlreadyInChain), _)) => {
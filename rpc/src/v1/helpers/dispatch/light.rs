@@ -277,8 +277,8 @@ where
 			.expect("no back-references; therefore all back-references are valid; qed")
 			.map(|bodies| {
 				bodies.into_iter().fold(Vec::new(), |mut v, block| {
-					for t in block.transaction_views().iter() {
-						v.push(t.gas_price())
+					for t in block.transactions().iter() {
+						v.push(t.gas_price)
 					}
 					v
 				})
@@ -408,6 +408,12 @@ pub fn transaction_message(error: &TransactionError) -> String {
 		InsufficientGasPrice { minimal, got } => {
 			format!("Transaction gas price is too low. It does not satisfy your node's minimal gas price (minimal: {}, got: {}). Try increasing the gas price.", minimal, got)
 		}
+		GasPriceLowerThanBaseFee { base_fee, got } => {
+			format!("Transaction gas price is lower than the block's base fee (base fee: {}, got: {}). Try increasing the gas price.", base_fee, got)
+		}
+		PriorityFeeAboveMaxFee { max_fee, got } => {
+			format!("Transaction max priority fee per gas is higher than its max fee per gas (max fee: {}, got: {}). Try lowering the priority fee.", max_fee, got)
+		}
 		InsufficientBalance { balance, cost } => {
 			format!("Insufficient funds. The account you tried to send transaction from does not have enough funds. Required {} and got: {}.", cost, balance)
 		}
@@ -423,6 +429,7 @@ pub fn transaction_message(error: &TransactionError) -> String {
 		NotAllowed => "Transaction is not permitted.".into(),
 		TooBig => "Transaction is too big, see chain specification for the limit.".into(),
 		InvalidRlp(ref descr) => format!("Invalid RLP data: {}", descr),
+		TransactionTypeNotEnabled(ref tx_type) => format!("Transaction type {:?} is not enabled on this chain yet.", tx_type),
 	}
 }
 
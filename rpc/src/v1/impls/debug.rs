@@ -66,6 +66,7 @@ impl<C: BlockChainClient + 'static> Debug for DebugClient<C> {
 					timestamp: block.header.timestamp().into(),
 					difficulty: cast(block.header.difficulty()),
 					total_difficulty: None,
+					base_fee_per_gas: block.header.base_fee_per_gas(),
 					seal_fields: block.header.seal().iter().cloned().map(Into::into).collect(),
 					uncles: block.uncles.iter().map(Header::hash).collect(),
 					transactions: BlockTransactions::Full(block.transactions
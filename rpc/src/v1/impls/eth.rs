@@ -20,7 +20,6 @@ use std::thread;
 use std::time::{Instant, Duration, SystemTime, UNIX_EPOCH};
 use std::sync::Arc;
 
-use rlp::Rlp;
 use ethereum_types::{Address, H64, H160, H256, U64, U256};
 use parking_lot::Mutex;
 
@@ -31,7 +30,7 @@ use ethcore::snapshot::SnapshotService;
 use hash::keccak;
 use miner::external::ExternalMinerService;
 use sync::SyncProvider;
-use types::transaction::{SignedTransaction, LocalizedTransaction};
+use types::transaction::{SignedTransaction, LocalizedTransaction, UnverifiedTransaction, TypedTxId};
 use types::BlockNumber as EthBlockNumber;
 use types::encoded;
 use types::filter::Filter as EthcoreFilter;
@@ -252,6 +251,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> EthClient<C, SN, S
 		match (block, difficulty) {
 			(Some(block), Some(total_difficulty)) => {
 				let view = block.header_view();
+				let eip1559_transition = self.client.engine().params().eip1559_transition;
 				Ok(Some(RichBlock {
 					inner: Block {
 						hash: match is_pending {
@@ -279,7 +279,8 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> EthClient<C, SN, S
 						timestamp: view.timestamp().into(),
 						difficulty: view.difficulty(),
 						total_difficulty: Some(total_difficulty),
-						seal_fields: view.seal().into_iter().map(Into::into).collect(),
+						base_fee_per_gas: view.base_fee_per_gas(eip1559_transition),
+						seal_fields: view.seal(eip1559_transition).into_iter().map(Into::into).collect(),
 						uncles: block.uncle_hashes(),
 						transactions: match include_txs {
 							true => BlockTransactions::Full(block.view().localized_transactions().into_iter().map(Transaction::from_localized).collect()),
@@ -377,7 +378,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> EthClient<C, SN, S
 				let uncle_id = UncleId { block: block_id, position };
 
 				let uncle = match client.uncle(uncle_id) {
-					Some(hdr) => match hdr.decode() {
+					Some(hdr) => match hdr.decode(client.engine().params().eip1559_transition) {
 						Ok(h) => h,
 						Err(e) => return Err(errors::decode(e))
 					},
@@ -416,6 +417,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> EthClient<C, SN, S
 				timestamp: uncle.timestamp().into(),
 				difficulty: *uncle.difficulty(),
 				total_difficulty: Some(uncle.difficulty() + parent_difficulty),
+				base_fee_per_gas: uncle.base_fee_per_gas(),
 				receipts_root: *uncle.receipts_root(),
 				extra_data: uncle.extra_data().clone().into(),
 				seal_fields: uncle.seal().iter().cloned().map(Into::into).collect(),
@@ -887,8 +889,17 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 	}
 
 	fn send_raw_transaction(&self, raw: Bytes) -> Result<H256> {
-		Rlp::new(&raw.into_vec()).as_val()
+		UnverifiedTransaction::decode_raw(&raw.into_vec())
 			.map_err(errors::rlp)
+			.and_then(|tx| {
+				// Local transactions skip the queue's basic verification, which is the only
+				// place checking whether a typed transaction is enabled yet.
+				if tx.tx_type() != TypedTxId::Legacy {
+					self.client.engine().verify_transaction_basic(&tx, &self.client.best_block_header())
+						.map_err(errors::transaction)?;
+				}
+				Ok(tx)
+			})
 			.and_then(|tx| SignedTransaction::new(tx).map_err(errors::transaction))
 			.and_then(|signed_transaction| {
 				FullDispatcher::dispatch_transaction(
@@ -926,7 +937,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 			};
 
 			let state = try_bf!(self.client.state_at(id).ok_or_else(errors::state_pruned));
-			let header = try_bf!(self.client.block_header(id).ok_or_else(errors::state_pruned).and_then(|h| h.decode().map_err(errors::decode)));
+			let header = try_bf!(self.client.block_header(id).ok_or_else(errors::state_pruned).and_then(|h| h.decode(self.client.engine().params().eip1559_transition).map_err(errors::decode)));
 
 			(state, header)
 		};
@@ -970,7 +981,7 @@ impl<C, SN: ?Sized, S: ?Sized, M, EM, T: StateInfo + 'static> Eth for EthClient<
 								.ok_or_else(errors::state_pruned));
 			let header = try_bf!(self.client.block_header(id)
 								 .ok_or_else(errors::state_pruned)
-								 .and_then(|h| h.decode().map_err(errors::decode)));
+								 .and_then(|h| h.decode(self.client.engine().params().eip1559_transition).map_err(errors::decode)));
 			(state, header)
 		};
 
@@ -31,8 +31,7 @@ use light::on_demand::{request, OnDemandRequester};
 use ethereum_types::{Address, H64, H160, H256, U64, U256};
 use hash::{KECCAK_NULL_RLP, KECCAK_EMPTY_LIST_RLP};
 use parking_lot::{RwLock, Mutex};
-use rlp::Rlp;
-use types::transaction::SignedTransaction;
+use types::transaction::{SignedTransaction, UnverifiedTransaction};
 use types::encoded;
 use types::filter::Filter as EthcoreFilter;
 use types::ids::BlockId;
@@ -139,7 +138,7 @@ where
 
 		// helper for filling out a rich block once we've got a block and a score.
 		let fill_rich = move |block: encoded::Block, score: Option<U256>| {
-			let header = block.decode_header();
+			let header = block.decode_header(engine.params().eip1559_transition);
 			let extra_info = engine.extra_info(&header);
 			RichBlock {
 				inner: Block {
@@ -159,6 +158,7 @@ where
 					timestamp: header.timestamp().into(),
 					difficulty: *header.difficulty(),
 					total_difficulty: score.map(Into::into),
+					base_fee_per_gas: header.base_fee_per_gas(),
 					seal_fields: header.seal().iter().cloned().map(Into::into).collect(),
 					uncles: block.uncle_hashes().into_iter().map(Into::into).collect(),
 					transactions: match include_txs {
@@ -378,9 +378,11 @@ where
 	}
 
 	fn send_raw_transaction(&self, raw: Bytes) -> Result<H256> {
-		let best_header = self.client.best_block_header().decode().map_err(errors::decode)?;
+		let best_header = self.client.best_block_header()
+			.decode(self.client.engine().params().eip1559_transition)
+			.map_err(errors::decode)?;
 
-		Rlp::new(&raw.into_vec()).as_val()
+		UnverifiedTransaction::decode_raw(&raw.into_vec())
 			.map_err(errors::rlp)
 			.and_then(|tx| {
 				self.client.engine().verify_transaction_basic(&tx, &best_header)
@@ -573,7 +575,7 @@ where
 }
 
 fn extract_uncle_at_index<T: LightChainClient>(block: encoded::Block, index: Index, client: Arc<T>) -> Option<RichBlock> {
-		let uncle = match block.uncles().into_iter().nth(index.value()) {
+		let uncle = match block.uncles(client.engine().params().eip1559_transition).into_iter().nth(index.value()) {
 			Some(u) => u,
 			None => return None,
 		};
@@ -596,6 +598,7 @@ fn extract_uncle_at_index<T: LightChainClient>(block: encoded::Block, index: Ind
 				timestamp: uncle.timestamp().into(),
 				difficulty: *uncle.difficulty(),
 				total_difficulty: None,
+				base_fee_per_gas: uncle.base_fee_per_gas(),
 				receipts_root: *uncle.receipts_root(),
 				extra_data: uncle.extra_data().clone().into(),
 				seal_fields: uncle.seal().iter().cloned().map(Into::into).collect(),
@@ -339,7 +339,7 @@ where
 
 		let engine = self.light_dispatch.client.engine().clone();
 		let from_encoded = move |encoded: encoded::Header| {
-			let header = encoded.decode().map_err(errors::decode)?;
+			let header = encoded.decode(engine.params().eip1559_transition).map_err(errors::decode)?;
 			let extra_info = engine.extra_info(&header);
 			Ok(RichHeader {
 				inner: Header {
@@ -358,6 +358,7 @@ where
 					logs_bloom: *header.log_bloom(),
 					timestamp: header.timestamp().into(),
 					difficulty: *header.difficulty(),
+					base_fee_per_gas: header.base_fee_per_gas(),
 					seal_fields: header.seal().iter().cloned().map(Into::into).collect(),
 					extra_data: Bytes::new(header.extra_data().clone()),
 				},
@@ -21,7 +21,7 @@ use std::collections::BTreeMap;
 
 use crypto::DEFAULT_MAC;
 use ethereum_types::{Address, H64, H160, H256, H512, U64, U256};
-use ethcore::client::{BlockChainClient, StateClient, Call};
+use ethcore::client::{BlockChainClient, StateClient, Call, EngineInfo};
 use ethcore::miner::{self, MinerService};
 use ethcore::snapshot::{SnapshotService, RestorationStatus};
 use ethcore::state::StateInfo;
@@ -45,7 +45,7 @@ use v1::types::{
 	TransactionStats, LocalTransactionStatus,
 	BlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, ChainStatus, Log, Filter,
-	RichHeader, Receipt, RecoveredAccount,
+	Header, RichHeader, Receipt, RecoveredAccount,
 	block_number_to_id
 };
 use Host;
@@ -97,7 +97,7 @@ impl<C, M, U> ParityClient<C, M, U> where
 
 impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 	S: StateInfo + 'static,
-	C: miner::BlockChainClient + BlockChainClient + StateClient<State=S> + Call<State=S> + 'static,
+	C: miner::BlockChainClient + BlockChainClient + StateClient<State=S> + Call<State=S> + EngineInfo + 'static,
 	M: MinerService<State=S> + 'static,
 	U: UpdateService + 'static,
 {
@@ -367,7 +367,7 @@ impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 		};
 
 		Box::new(future::ok(RichHeader {
-			inner: header.into(),
+			inner: Header::new(&header, self.client.engine().params().eip1559_transition),
 			extra_info: extra.unwrap_or_default(),
 		}))
 	}
@@ -423,7 +423,7 @@ impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 			};
 
 			let state = self.client.state_at(id).ok_or_else(errors::state_pruned)?;
-			let header = self.client.block_header(id).ok_or_else(errors::state_pruned)?.decode().map_err(errors::decode)?;
+			let header = self.client.block_header(id).ok_or_else(errors::state_pruned)?.decode(self.client.engine().params().eip1559_transition).map_err(errors::decode)?;
 
 			(state, header)
 		};
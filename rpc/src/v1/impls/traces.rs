@@ -18,10 +18,9 @@
 
 use std::sync::Arc;
 
-use ethcore::client::{BlockChainClient, CallAnalytics, TransactionId, TraceId, StateClient, StateInfo, Call, BlockId};
+use ethcore::client::{BlockChainClient, CallAnalytics, TransactionId, TraceId, StateClient, StateInfo, Call, BlockId, EngineInfo};
 use ethereum_types::H256;
-use rlp::Rlp;
-use types::transaction::SignedTransaction;
+use types::transaction::{SignedTransaction, UnverifiedTransaction};
 
 use jsonrpc_core::Result;
 use v1::Metadata;
@@ -54,7 +53,7 @@ impl<C> TracesClient<C> {
 
 impl<C, S> Traces for TracesClient<C> where
 	S: StateInfo + 'static,
-	C: BlockChainClient + StateClient<State=S> + Call<State=S> + 'static
+	C: BlockChainClient + StateClient<State=S> + Call<State=S> + EngineInfo + 'static
 {
 	type Metadata = Metadata;
 
@@ -105,7 +104,7 @@ impl<C, S> Traces for TracesClient<C> where
 		let mut state = self.client.state_at(id).ok_or_else(errors::state_pruned)?;
 		let header = self.client.block_header(id).ok_or_else(errors::state_pruned)?;
 
-		self.client.call(&signed, to_call_analytics(flags), &mut state, &header.decode().map_err(errors::decode)?)
+		self.client.call(&signed, to_call_analytics(flags), &mut state, &header.decode(self.client.engine().params().eip1559_transition).map_err(errors::decode)?)
 			.map(TraceResults::from)
 			.map_err(errors::call)
 	}
@@ -132,7 +131,7 @@ impl<C, S> Traces for TracesClient<C> where
 		let mut state = self.client.state_at(id).ok_or_else(errors::state_pruned)?;
 		let header = self.client.block_header(id).ok_or_else(errors::state_pruned)?;
 
-		self.client.call_many(&requests, &mut state, &header.decode().map_err(errors::decode)?)
+		self.client.call_many(&requests, &mut state, &header.decode(self.client.engine().params().eip1559_transition).map_err(errors::decode)?)
 			.map(|results| results.into_iter().map(TraceResults::from).collect())
 			.map_err(errors::call)
 	}
@@ -140,7 +139,7 @@ impl<C, S> Traces for TracesClient<C> where
 	fn raw_transaction(&self, raw_transaction: Bytes, flags: TraceOptions, block: Option<BlockNumber>) -> Result<TraceResults> {
 		let block = block.unwrap_or_default();
 
-		let tx = Rlp::new(&raw_transaction.into_vec()).as_val().map_err(|e| errors::invalid_params("Transaction is not valid RLP", e))?;
+		let tx = UnverifiedTransaction::decode_raw(&raw_transaction.into_vec()).map_err(|e| errors::invalid_params("Transaction is not valid RLP", e))?;
 		let signed = SignedTransaction::new(tx).map_err(errors::transaction)?;
 
 		let id = match block {
@@ -154,7 +153,7 @@ impl<C, S> Traces for TracesClient<C> where
 		let mut state = self.client.state_at(id).ok_or_else(errors::state_pruned)?;
 		let header = self.client.block_header(id).ok_or_else(errors::state_pruned)?;
 
-		self.client.call(&signed, to_call_analytics(flags), &mut state, &header.decode().map_err(errors::decode)?)
+		self.client.call(&signed, to_call_analytics(flags), &mut state, &header.decode(self.client.engine().params().eip1559_transition).map_err(errors::decode)?)
 			.map(TraceResults::from)
 			.map_err(errors::call)
 	}
@@ -93,8 +93,9 @@ impl EthTester {
 			Self::from_spec(make_spec(chain))
 		};
 
+		let eip1559_transition = tester.client.engine().params().eip1559_transition;
 		for b in chain.blocks_rlp() {
-			if let Ok(block) = Unverified::from_rlp(b) {
+			if let Ok(block) = Unverified::from_rlp(b, eip1559_transition) {
 				let _ = tester.client.import_block(block);
 				tester.client.flush_queue();
 				tester.client.import_verified_blocks();
@@ -474,7 +475,8 @@ fn verify_transaction_counts(name: String, chain: BlockChain) {
 	let tester = EthTester::from_chain(&chain);
 
 	let mut id = 1;
-	for b in chain.blocks_rlp().into_iter().filter_map(|b| Unverified::from_rlp(b).ok()) {
+	let eip1559_transition = tester.client.engine().params().eip1559_transition;
+	for b in chain.blocks_rlp().into_iter().filter_map(|b| Unverified::from_rlp(b, eip1559_transition).ok()) {
 		let count = b.transactions.len();
 
 		let hash = b.header.hash();
@@ -845,6 +845,31 @@ fn rpc_eth_send_raw_transaction() {
 	assert_eq!(tester.io.handle_request_sync(&req), Some(res));
 }
 
+#[test]
+fn rpc_eth_send_raw_transaction_rejects_typed_transaction_before_transition() {
+	use ethkey::{Random, Generator};
+	use types::transaction::AccessListTransaction;
+
+	let tester = EthTester::default();
+	let keypair = Random.generate().unwrap();
+	let t = AccessListTransaction {
+		gas: U256::from(21_000),
+		..Default::default()
+	}.sign(keypair.secret());
+
+	let req = r#"{
+		"jsonrpc": "2.0",
+		"method": "eth_sendRawTransaction",
+		"params": [
+			"0x"#.to_owned() + &t.encode_raw().to_hex() + r#""
+		],
+		"id": 1
+	}"#;
+	let res = r#"{"jsonrpc":"2.0","error":{"code":-32010,"message":"Transaction type AccessList is not enabled on this chain yet."},"id":1}"#.into();
+
+	assert_eq!(tester.io.handle_request_sync(&req), Some(res));
+}
+
 #[test]
 fn rpc_eth_transaction_receipt() {
 	let receipt = LocalizedReceipt {
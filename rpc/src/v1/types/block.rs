@@ -21,6 +21,7 @@ use ethereum_types::{H160, H256, U256, Bloom as H2048};
 use serde::ser::Error;
 use serde::{Serialize, Serializer};
 use types::encoded::Header as EthHeader;
+use types::BlockNumber as EthBlockNumber;
 use v1::types::{Bytes, Transaction};
 
 /// Block Transactions
@@ -79,6 +80,9 @@ pub struct Block {
 	pub difficulty: U256,
 	/// Total difficulty
 	pub total_difficulty: Option<U256>,
+	/// Base fee per gas (EIP-1559)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub base_fee_per_gas: Option<U256>,
 	/// Seal fields
 	pub seal_fields: Vec<Bytes>,
 	/// Uncles' hashes
@@ -124,20 +128,19 @@ pub struct Header {
 	pub timestamp: U256,
 	/// Difficulty
 	pub difficulty: U256,
+	/// Base fee per gas (EIP-1559)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub base_fee_per_gas: Option<U256>,
 	/// Seal fields
 	pub seal_fields: Vec<Bytes>,
 	/// Size in bytes
 	pub size: Option<U256>,
 }
 
-impl From<EthHeader> for Header {
-	fn from(h: EthHeader) -> Self {
-		(&h).into()
-	}
-}
-
-impl<'a> From<&'a EthHeader> for Header {
-	fn from(h: &'a EthHeader) -> Self {
+impl Header {
+	/// Create a header representation from an encoded header. Headers at or after
+	/// `eip1559_transition` carry a base fee, which is not one of the seal fields.
+	pub fn new(h: &EthHeader, eip1559_transition: EthBlockNumber) -> Self {
 		Header {
 			hash: Some(h.hash()),
 			size: Some(h.rlp().as_raw().len().into()),
@@ -155,7 +158,8 @@ impl<'a> From<&'a EthHeader> for Header {
 			timestamp: h.timestamp().into(),
 			difficulty: h.difficulty(),
 			extra_data: h.extra_data().into(),
-			seal_fields: h.view().decode_seal()
+			base_fee_per_gas: h.base_fee_per_gas(eip1559_transition),
+			seal_fields: h.view().decode_seal(eip1559_transition)
 				.expect("Client/Miner returns only valid headers. We only serialize headers from Client/Miner; qed")
 				.into_iter().map(Into::into).collect(),
 		}
@@ -240,6 +244,7 @@ mod tests {
 			timestamp: U256::default(),
 			difficulty: U256::default(),
 			total_difficulty: Some(U256::default()),
+			base_fee_per_gas: None,
 			seal_fields: vec![Bytes::default(), Bytes::default()],
 			uncles: vec![],
 			transactions: BlockTransactions::Hashes(vec![].into()),
@@ -278,6 +283,7 @@ mod tests {
 			timestamp: U256::default(),
 			difficulty: U256::default(),
 			total_difficulty: Some(U256::default()),
+			base_fee_per_gas: None,
 			seal_fields: vec![Bytes::default(), Bytes::default()],
 			uncles: vec![],
 			transactions: BlockTransactions::Hashes(vec![].into()),
@@ -315,6 +321,7 @@ mod tests {
 			logs_bloom: H2048::default(),
 			timestamp: U256::default(),
 			difficulty: U256::default(),
+			base_fee_per_gas: None,
 			seal_fields: vec![Bytes::default(), Bytes::default()],
 			size: Some(69.into()),
 		};
@@ -331,4 +338,31 @@ mod tests {
 		assert_eq!(serialized_header, r#"{"hash":"0x0000000000000000000000000000000000000000000000000000000000000000","parentHash":"0x0000000000000000000000000000000000000000000000000000000000000000","sha3Uncles":"0x0000000000000000000000000000000000000000000000000000000000000000","author":"0x0000000000000000000000000000000000000000","miner":"0x0000000000000000000000000000000000000000","stateRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","transactionsRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","receiptsRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","number":"0x0","gasUsed":"0x0","gasLimit":"0x0","extraData":"0x","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","timestamp":"0x0","difficulty":"0x0","sealFields":["0x","0x"],"size":"0x45"}"#);
 		assert_eq!(serialized_rich_header, r#"{"author":"0x0000000000000000000000000000000000000000","difficulty":"0x0","extraData":"0x","gasLimit":"0x0","gasUsed":"0x0","hash":"0x0000000000000000000000000000000000000000000000000000000000000000","logsBloom":"0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000","miner":"0x0000000000000000000000000000000000000000","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","nonce":"0x0000000000000000","number":"0x0","parentHash":"0x0000000000000000000000000000000000000000000000000000000000000000","receiptsRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","sealFields":["0x","0x"],"sha3Uncles":"0x0000000000000000000000000000000000000000000000000000000000000000","size":"0x45","stateRoot":"0x0000000000000000000000000000000000000000000000000000000000000000","timestamp":"0x0","transactionsRoot":"0x0000000000000000000000000000000000000000000000000000000000000000"}"#);
 	}
+
+	#[test]
+	fn test_serialize_header_with_base_fee() {
+		let header = Header {
+			hash: Some(H256::default()),
+			parent_hash: H256::default(),
+			uncles_hash: H256::default(),
+			author: H160::default(),
+			miner: H160::default(),
+			state_root: H256::default(),
+			transactions_root: H256::default(),
+			receipts_root: H256::default(),
+			number: Some(U256::default()),
+			gas_used: U256::default(),
+			gas_limit: U256::default(),
+			extra_data: Bytes::default(),
+			logs_bloom: H2048::default(),
+			timestamp: U256::default(),
+			difficulty: U256::default(),
+			base_fee_per_gas: Some(1_000_000_000.into()),
+			seal_fields: vec![],
+			size: Some(69.into()),
+		};
+		let serialized_header = serde_json::to_string(&header).unwrap();
+
+		assert!(serialized_header.contains(r#""difficulty":"0x0","baseFeePerGas":"0x3b9aca00","sealFields":[]"#));
+	}
 }
@@ -173,6 +173,7 @@ mod tests {
 				logs_bloom: Default::default(),
 				timestamp: Default::default(),
 				difficulty: Default::default(),
+				base_fee_per_gas: None,
 				seal_fields: vec![Default::default(), Default::default()],
 				size: Some(69.into()),
 			},